@@ -3,8 +3,10 @@ use std::cmp::Ordering;
 use itertools::Itertools;
 use rand::Rng;
 use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
 
 use crate::cards::{Card, Rank};
+use crate::range::HandRange;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(u8)]
@@ -217,6 +219,502 @@ pub fn compare_strength(a: HandStrength, b: HandStrength) -> Ordering {
     a.cmp(&b)
 }
 
+/// What kind of draw a bucket of outs belongs to, for a "9 flush outs" style breakdown
+/// rather than one aggregate number. Buckets aren't mutually exclusive - a card can be
+/// both a flush out and a straight out at once - so `draw_summary` reports them
+/// independently rather than partitioning the single `outs` total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DrawKind {
+    FlushDraw,
+    OpenEndedStraight,
+    Gutshot,
+    Overcards,
+    SetOrTrips,
+    TwoPairOrBetter,
+}
+
+impl DrawKind {
+    pub fn label(self) -> &'static str {
+        match self {
+            DrawKind::FlushDraw => "flush draw",
+            DrawKind::OpenEndedStraight => "open-ended straight draw",
+            DrawKind::Gutshot => "gutshot straight draw",
+            DrawKind::Overcards => "overcards",
+            DrawKind::SetOrTrips => "set/trips draw",
+            DrawKind::TwoPairOrBetter => "two pair or better draw",
+        }
+    }
+}
+
+/// Outs-and-equity summary for an in-progress hand. Only meaningful on the flop or
+/// turn, since there's no "draw" once all five board cards are out (or none at all).
+/// `outs` counts single cards that would improve hero past the current hand category;
+/// `draw_types` counts, per kind of draw (flush, straight, overcards, ...), the outs
+/// that complete it; the buckets can overlap, so they don't sum to `outs` (see
+/// `DrawKind`); `rule_estimate_pct` is the classic
+/// rule-of-4-and-2 shortcut (outs times 4 on the flop, times 2 on the turn); `exact_pct`
+/// is the true probability of improving by the river, found by enumerating the actual
+/// remaining runouts, so a viewer can see how far the shortcut drifts from the exact
+/// number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawSummary {
+    pub outs: u8,
+    pub draw_types: Vec<(DrawKind, u8)>,
+    pub cards_to_come: u8,
+    pub rule_estimate_pct: f32,
+    pub exact_pct: f32,
+}
+
+fn rank_mask(cards: &[Card]) -> u32 {
+    let mut mask = 0u32;
+    for card in cards {
+        let value = card.rank_value();
+        mask |= 1 << value;
+        if value == Rank::Ace.value() {
+            mask |= 1 << 1; // also covers the ace-low end of a wheel straight
+        }
+    }
+    mask
+}
+
+/// Counts outs that would complete a four-flush, if hero+board already hold exactly
+/// four cards of one suit.
+fn flush_draw_outs(made_so_far: &[Card], remaining: &[Card]) -> Option<u8> {
+    let suit = *crate::cards::Suit::ALL
+        .iter()
+        .find(|&&suit| made_so_far.iter().filter(|c| c.suit == suit).count() == 4)?;
+    let outs = remaining.iter().filter(|c| c.suit == suit).count() as u8;
+    (outs > 0).then_some(outs)
+}
+
+/// Splits the straight-completing ranks of `mask` into open-ended (missing rank sits at
+/// either end of an otherwise-consecutive five-rank window) and gutshot (missing rank
+/// is sandwiched between made ranks) groups.
+fn straight_draw_ranks(mask: u32) -> (Vec<u8>, Vec<u8>) {
+    let mut open = Vec::new();
+    let mut gut = Vec::new();
+    for high in 5u8..=14 {
+        let window = [high, high - 1, high - 2, high - 3, high - 4];
+        let missing: Vec<(usize, u8)> = window
+            .iter()
+            .enumerate()
+            .filter(|&(_, &rank)| mask & (1 << rank) == 0)
+            .map(|(idx, &rank)| (idx, rank))
+            .collect();
+        if let [(idx, rank)] = missing[..] {
+            let actual_rank = if rank == 1 { Rank::Ace.value() } else { rank };
+            if idx == 0 || idx == 4 {
+                open.push(actual_rank);
+            } else {
+                gut.push(actual_rank);
+            }
+        }
+    }
+    open.sort_unstable();
+    open.dedup();
+    gut.retain(|rank| !open.contains(rank));
+    gut.sort_unstable();
+    gut.dedup();
+    (open, gut)
+}
+
+fn straight_draw_outs(made_so_far: &[Card], remaining: &[Card]) -> (Option<u8>, Option<u8>) {
+    let (open_ranks, gut_ranks) = straight_draw_ranks(rank_mask(made_so_far));
+    let count = |ranks: &[u8]| {
+        let outs = remaining
+            .iter()
+            .filter(|c| ranks.contains(&c.rank_value()))
+            .count() as u8;
+        (outs > 0).then_some(outs)
+    };
+    (count(&open_ranks), count(&gut_ranks))
+}
+
+/// Outs that would pair one of hero's hole cards into the best possible one-pair hand,
+/// only meaningful while hero has no pair (or better) at all yet.
+fn overcard_outs(hero: &[Card; 2], board: &[Card], baseline: HandCategory, remaining: &[Card]) -> Option<u8> {
+    if baseline != HandCategory::HighCard {
+        return None;
+    }
+    let board_max = board.iter().map(|c| c.rank_value()).max().unwrap_or(0);
+    let over_ranks: Vec<u8> = hero
+        .iter()
+        .map(|c| c.rank_value())
+        .filter(|&rank| rank > board_max)
+        .collect();
+    if over_ranks.is_empty() {
+        return None;
+    }
+    let outs = remaining
+        .iter()
+        .filter(|c| over_ranks.contains(&c.rank_value()))
+        .count() as u8;
+    (outs > 0).then_some(outs)
+}
+
+/// Outs that turn hero's pocket pair into a set (trips), only meaningful when hero
+/// holds a pocket pair the board hasn't already tripped up.
+fn set_or_trips_outs(hero: &[Card; 2], board: &[Card], remaining: &[Card]) -> Option<u8> {
+    if hero[0].rank != hero[1].rank || board.iter().any(|c| c.rank == hero[0].rank) {
+        return None;
+    }
+    let outs = remaining.iter().filter(|c| c.rank == hero[0].rank).count() as u8;
+    (outs > 0).then_some(outs)
+}
+
+/// Outs that pair a second rank on top of hero's current one pair - either hero's other
+/// hole card or an unmatched board card - reaching two pair or (if the board pairs up)
+/// trips. Only meaningful when hero has exactly one pair and it isn't a pocket pair
+/// (see `set_or_trips_outs` for that case).
+fn two_pair_or_better_outs(
+    hero: &[Card; 2],
+    board: &[Card],
+    baseline: HandCategory,
+    remaining: &[Card],
+) -> Option<u8> {
+    if hero[0].rank == hero[1].rank || baseline != HandCategory::OnePair {
+        return None;
+    }
+    let mut target_ranks: Vec<Rank> = Vec::new();
+    for card in hero {
+        if !board.iter().any(|b| b.rank == card.rank) {
+            target_ranks.push(card.rank);
+        }
+    }
+    for card in board {
+        if !hero.iter().any(|h| h.rank == card.rank) {
+            target_ranks.push(card.rank);
+        }
+    }
+    target_ranks.sort_by_key(|r| r.value());
+    target_ranks.dedup();
+    let outs = remaining
+        .iter()
+        .filter(|c| target_ranks.contains(&c.rank))
+        .count() as u8;
+    (outs > 0).then_some(outs)
+}
+
+pub fn draw_summary(hero: &[Card; 2], board: &[Card]) -> Option<DrawSummary> {
+    let cards_to_come = 5usize.checked_sub(board.len())?;
+    if cards_to_come == 0 || cards_to_come > 2 {
+        return None;
+    }
+
+    let mut made_so_far = Vec::with_capacity(hero.len() + board.len());
+    made_so_far.extend_from_slice(hero);
+    made_so_far.extend_from_slice(board);
+    let baseline = best_five_card_hand(&made_so_far).category;
+
+    let remaining: Vec<Card> = crate::cards::standard_deck()
+        .into_iter()
+        .filter(|c| !hero.contains(c) && !board.contains(c))
+        .collect();
+
+    let improves = |extra: &[Card]| -> bool {
+        let mut combo = made_so_far.clone();
+        combo.extend_from_slice(extra);
+        best_five_card_hand(&combo).category > baseline
+    };
+
+    let outs = remaining.iter().filter(|&&card| improves(&[card])).count() as u8;
+
+    let (open_ended, gutshot) = straight_draw_outs(&made_so_far, &remaining);
+    let draw_types: Vec<(DrawKind, u8)> = [
+        flush_draw_outs(&made_so_far, &remaining).map(|n| (DrawKind::FlushDraw, n)),
+        open_ended.map(|n| (DrawKind::OpenEndedStraight, n)),
+        gutshot.map(|n| (DrawKind::Gutshot, n)),
+        set_or_trips_outs(hero, board, &remaining).map(|n| (DrawKind::SetOrTrips, n)),
+        two_pair_or_better_outs(hero, board, baseline, &remaining).map(|n| (DrawKind::TwoPairOrBetter, n)),
+        overcard_outs(hero, board, baseline, &remaining).map(|n| (DrawKind::Overcards, n)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    let exact_pct = if cards_to_come == 1 {
+        outs as f32 / remaining.len() as f32 * 100.0
+    } else {
+        let total = remaining.len() * (remaining.len() - 1) / 2;
+        let favorable = remaining
+            .iter()
+            .copied()
+            .combinations(2)
+            .filter(|pair| improves(pair))
+            .count();
+        favorable as f32 / total.max(1) as f32 * 100.0
+    };
+
+    let rule_estimate_pct = (outs as f32 * if cards_to_come == 2 { 4.0 } else { 2.0 }).min(100.0);
+
+    Some(DrawSummary {
+        outs,
+        draw_types,
+        cards_to_come: cards_to_come as u8,
+        rule_estimate_pct,
+        exact_pct,
+    })
+}
+
+/// Hero's exact win share against every possible villain holding and board runout
+/// consistent with `hero`/`villain`/`board_known`, with no sampling error. `villain`
+/// enumerates every remaining two-card combo when `None`; `board_known` enumerates
+/// every remaining one- or two-card completion needed to reach five board cards. Split
+/// pots count as half a win each, same as `monte_carlo_equity`.
+///
+/// The combinatorial space this walks grows combinatorially with the number of unseen
+/// cards, so callers should only reach for this once `enumeration_size` reports a space
+/// small enough to be cheap - see `resolve_equity`, which makes that call automatically.
+pub fn exact_equity(hero: &[Card], villain: Option<&[Card]>, board_known: &[Card]) -> f32 {
+    assert!(hero.len() == 2, "hero must have two cards");
+
+    let mut used: Vec<Card> = Vec::with_capacity(9);
+    used.extend_from_slice(hero);
+    if let Some(villain_cards) = villain {
+        used.extend_from_slice(villain_cards);
+    }
+    used.extend_from_slice(board_known);
+
+    let remaining: Vec<Card> = crate::cards::standard_deck()
+        .into_iter()
+        .filter(|c| !used.contains(c))
+        .collect();
+    let cards_needed = 5usize.saturating_sub(board_known.len());
+
+    let mut wins = 0.0f32;
+    let mut total = 0u64;
+
+    let mut score_runout = |villain_cards: &[Card], board_extra: &[Card]| {
+        let mut board = board_known.to_vec();
+        board.extend_from_slice(board_extra);
+        let hero_cards: Vec<Card> = hero.iter().copied().chain(board.iter().copied()).collect();
+        let villain_full: Vec<Card> = villain_cards
+            .iter()
+            .copied()
+            .chain(board.iter().copied())
+            .collect();
+        match compare_strength(best_five_card_hand(&hero_cards), best_five_card_hand(&villain_full)) {
+            Ordering::Greater => wins += 1.0,
+            Ordering::Equal => wins += 0.5,
+            Ordering::Less => {}
+        }
+        total += 1;
+    };
+
+    match villain {
+        Some(villain_cards) => {
+            for board_combo in remaining.iter().copied().combinations(cards_needed) {
+                score_runout(villain_cards, &board_combo);
+            }
+        }
+        None => {
+            for villain_combo in remaining.iter().copied().combinations(2) {
+                let rest: Vec<Card> = remaining
+                    .iter()
+                    .copied()
+                    .filter(|c| !villain_combo.contains(c))
+                    .collect();
+                for board_combo in rest.into_iter().combinations(cards_needed) {
+                    score_runout(&villain_combo, &board_combo);
+                }
+            }
+        }
+    }
+
+    wins / total.max(1) as f32
+}
+
+/// Size of the combinatorial space `exact_equity` would have to walk for a spot with
+/// `unseen` undealt cards and `cards_to_come` board cards still missing, given whether
+/// villain's hand is known. Used by `resolve_equity` to decide whether exact enumeration
+/// is cheap enough to use in place of Monte Carlo sampling.
+pub fn enumeration_size(villain_known: bool, unseen: usize, cards_to_come: usize) -> u64 {
+    let board_combos = choose(unseen as u64, cards_to_come as u64);
+    if villain_known {
+        board_combos
+    } else {
+        let villain_combos = choose(unseen as u64, 2);
+        let remaining_after_villain = unseen.saturating_sub(2) as u64;
+        villain_combos * choose(remaining_after_villain, cards_to_come as u64)
+    }
+}
+
+fn choose(n: u64, k: u64) -> u64 {
+    if k > n {
+        return 0;
+    }
+    let k = k.min(n - k);
+    let mut result = 1u64;
+    for i in 0..k {
+        result = result * (n - i) / (i + 1);
+    }
+    result
+}
+
+/// Chooses exact enumeration when the board/villain combinations still possible fit
+/// within `max_enumeration`, falling back to `samples`-sample Monte Carlo otherwise,
+/// so low-card-count rivers (and often turns) come back with zero sampling noise while
+/// wider spots like an unraised flop still run cheaply via Monte Carlo. `session`
+/// computes its equity previews through the range variant, `resolve_equity_vs_range`,
+/// which makes the same choice.
+pub fn resolve_equity<R: Rng + ?Sized>(
+    hero: &[Card],
+    villain: Option<&[Card]>,
+    board_known: &[Card],
+    samples: u32,
+    max_enumeration: u64,
+    rng: &mut R,
+) -> f32 {
+    let mut used = hero.len() + board_known.len();
+    if let Some(villain_cards) = villain {
+        used += villain_cards.len();
+    }
+    let unseen = 52usize.saturating_sub(used);
+    let cards_to_come = 5usize.saturating_sub(board_known.len());
+    let space = enumeration_size(villain.is_some(), unseen, cards_to_come);
+
+    if space <= max_enumeration {
+        exact_equity(hero, villain, board_known)
+    } else {
+        monte_carlo_equity(hero, villain, board_known, samples, rng)
+    }
+}
+
+/// Hero's weighted win share against every live combo in `range` (those not colliding
+/// with `hero` or `board_known`), enumerating every remaining board runout exactly for
+/// each. Each combo's contribution is scaled by its range weight, so a combo that chops
+/// counts for `weight * 0.5` rather than a flat half-win. Returns `0.5` if the range has
+/// no combo left that doesn't collide with `hero`/`board_known`.
+pub fn exact_equity_vs_range(hero: &[Card], range: &HandRange, board_known: &[Card]) -> f32 {
+    assert!(hero.len() == 2, "hero must have two cards");
+
+    let mut dead: Vec<Card> = Vec::with_capacity(7);
+    dead.extend_from_slice(hero);
+    dead.extend_from_slice(board_known);
+    let live = range.combos_excluding(&dead);
+    let cards_needed = 5usize.saturating_sub(board_known.len());
+
+    let mut wins = 0.0f32;
+    let mut total = 0.0f32;
+
+    for combo in &live {
+        let villain_cards = [combo.cards.0, combo.cards.1];
+        let remaining: Vec<Card> = crate::cards::standard_deck()
+            .into_iter()
+            .filter(|c| !dead.contains(c) && !villain_cards.contains(c))
+            .collect();
+
+        for board_combo in remaining.iter().copied().combinations(cards_needed) {
+            let mut board = board_known.to_vec();
+            board.extend_from_slice(&board_combo);
+            let hero_cards: Vec<Card> = hero.iter().copied().chain(board.iter().copied()).collect();
+            let villain_full: Vec<Card> = villain_cards
+                .iter()
+                .copied()
+                .chain(board.iter().copied())
+                .collect();
+            match compare_strength(best_five_card_hand(&hero_cards), best_five_card_hand(&villain_full)) {
+                Ordering::Greater => wins += combo.weight,
+                Ordering::Equal => wins += combo.weight * 0.5,
+                Ordering::Less => {}
+            }
+            total += combo.weight;
+        }
+    }
+
+    if total <= 0.0 { 0.5 } else { wins / total }
+}
+
+/// Size of the combinatorial space `exact_equity_vs_range` would walk: the number of
+/// range combos still live against `hero`/`board_known`, times the board completions
+/// possible for each.
+pub fn enumeration_size_vs_range(live_combo_count: usize, unseen_after_villain: usize, cards_to_come: usize) -> u64 {
+    live_combo_count as u64 * choose(unseen_after_villain as u64, cards_to_come as u64)
+}
+
+/// Like `resolve_equity`, but against every live combo in `range` instead of a single
+/// known hand or a uniform random one.
+pub fn resolve_equity_vs_range<R: Rng + ?Sized>(
+    hero: &[Card],
+    range: &HandRange,
+    board_known: &[Card],
+    samples: u32,
+    max_enumeration: u64,
+    rng: &mut R,
+) -> f32 {
+    let mut dead: Vec<Card> = Vec::with_capacity(7);
+    dead.extend_from_slice(hero);
+    dead.extend_from_slice(board_known);
+    let live_count = range.combos_excluding(&dead).len();
+
+    let used = hero.len() + board_known.len() + 2;
+    let unseen_after_villain = 52usize.saturating_sub(used);
+    let cards_to_come = 5usize.saturating_sub(board_known.len());
+    let space = enumeration_size_vs_range(live_count, unseen_after_villain, cards_to_come);
+
+    if space <= max_enumeration {
+        exact_equity_vs_range(hero, range, board_known)
+    } else {
+        monte_carlo_equity_vs_range(hero, range, board_known, samples, rng)
+    }
+}
+
+/// Monte Carlo equity against `range`: each sample draws a villain combo weighted by
+/// `range` (filtered for card removal against `hero`/`board_known`) and a random board
+/// completion. Returns `0.5` if the range has no live combo at all.
+pub fn monte_carlo_equity_vs_range<R: Rng + ?Sized>(
+    hero: &[Card],
+    range: &HandRange,
+    board_known: &[Card],
+    samples: u32,
+    rng: &mut R,
+) -> f32 {
+    assert!(hero.len() == 2, "hero must have two cards");
+    let samples = samples.max(1);
+
+    let mut dead: Vec<Card> = Vec::with_capacity(7);
+    dead.extend_from_slice(hero);
+    dead.extend_from_slice(board_known);
+    let live = range.combos_excluding(&dead);
+    if live.is_empty() {
+        return 0.5;
+    }
+
+    let mut equity_sum = 0.0f32;
+    for _ in 0..samples {
+        let (villain_first, villain_second) = HandRange::sample_from(&live, rng);
+        let villain_cards = [villain_first, villain_second];
+
+        let mut deck: Vec<Card> = crate::cards::standard_deck()
+            .into_iter()
+            .filter(|c| !dead.contains(c) && !villain_cards.contains(c))
+            .collect();
+        deck.shuffle(rng);
+
+        let mut board = board_known.to_vec();
+        let cards_needed = 5usize.saturating_sub(board.len());
+        for _ in 0..cards_needed {
+            board.push(deck.pop().expect("cards remain"));
+        }
+
+        let hero_cards: Vec<Card> = hero.iter().copied().chain(board.iter().copied()).collect();
+        let villain_cards_full: Vec<Card> = villain_cards
+            .iter()
+            .copied()
+            .chain(board.iter().copied())
+            .collect();
+
+        match compare_strength(best_five_card_hand(&hero_cards), best_five_card_hand(&villain_cards_full)) {
+            Ordering::Greater => equity_sum += 1.0,
+            Ordering::Equal => equity_sum += 0.5,
+            Ordering::Less => {}
+        }
+    }
+
+    equity_sum / samples as f32
+}
+
 pub fn monte_carlo_equity<R: Rng + ?Sized>(
     hero: &[Card],
     villain: Option<&[Card]>,
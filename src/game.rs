@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use crate::equity::DrawSummary;
+use crate::range::RangeSummary;
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum Street {
@@ -11,6 +14,16 @@ pub enum Street {
     Terminal,
 }
 
+/// Heads-up table position. The button also posts the small blind and acts first
+/// preflop; the big blind acts first on every later street. `Session` applies this
+/// order preflop only: postflop, hero acts first on every street from either seat.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Position {
+    Button,
+    BigBlind,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
 pub enum HeroActionKind {
@@ -37,10 +50,17 @@ pub struct ActionOption {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NodeSnapshot {
     pub street: Street,
+    pub hero_position: Position,
     pub pot_bb: f32,
     pub effective_stack_bb: f32,
     pub board: Vec<String>,
     pub hero_cards: Vec<String>,
     pub rival_cards_known: bool,
     pub action_options: Vec<ActionOption>,
+    /// Outs and draw-equity analysis; `None` unless there's a draw to analyse (flop or
+    /// turn only, and only while villain's hand is hidden).
+    pub draw: Option<DrawSummary>,
+    /// The rival's assumed range of starting hands for the current street, for display;
+    /// `None` once villain's actual cards are known (hand completed).
+    pub rival_range: Option<RangeSummary>,
 }
@@ -1,10 +1,21 @@
 pub mod cards;
 pub mod equity;
 pub mod game;
+pub mod range;
+pub mod replay;
 pub mod rival;
+pub mod rival_strategy;
 pub mod session;
+pub mod simulator;
+pub mod solver;
+pub mod strategy;
 pub mod trainer;
 pub mod web;
 
+pub use range::HandRange;
+pub use replay::{ReplayExport, REPLAY_SCHEMA_VERSION};
 pub use rival::RivalStyle;
+pub use rival_strategy::{RivalDecision, RivalStrategy};
+pub use simulator::{SimulationReport, Simulator};
+pub use strategy::{BestEvStrategy, RandomStrategy, Strategy};
 pub use trainer::{ActionChoice, Trainer, TrainerConfig};
@@ -1,8 +1,10 @@
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand, ValueEnum};
+use clap::{Parser, Subcommand};
 use gto_trainer::rival::RivalStyle;
+use gto_trainer::rival_strategy;
 use gto_trainer::web;
 use gto_trainer::{Trainer, TrainerConfig};
 
@@ -29,17 +31,27 @@ struct Cli {
     #[arg(long = "mc", default_value_t = 200)]
     mc_samples: u32,
 
+    /// Largest board/villain-combo enumeration to walk exactly before falling back to
+    /// Monte Carlo sampling (see `equity::resolve_equity_vs_range`)
+    #[arg(long = "exact-equity-max", default_value_t = 50_000)]
+    exact_equity_threshold: u64,
+
     /// Disable ANSI colors in CLI output
     #[arg(long = "no-color", default_value_t = false)]
     no_color: bool,
 
-    /// Rival style preset
+    /// Rival strategy to play against (see `rival_strategy::strategies` for the full
+    /// registry of the three fixed presets plus any context-aware strategies).
     #[arg(long = "rival-style", default_value = "balanced")]
-    rival_style: RivalStyleArg,
+    rival_style: String,
 
     /// Auto-play hands using the best-EV action (useful for smoke tests)
     #[arg(long, default_value_t = false)]
     auto: bool,
+
+    /// Write a JSON hand-history export to this path once the session ends
+    #[arg(long)]
+    export: Option<PathBuf>,
 }
 
 #[derive(Debug, Subcommand)]
@@ -52,23 +64,6 @@ enum Commands {
     },
 }
 
-#[derive(Debug, Clone, ValueEnum)]
-enum RivalStyleArg {
-    Balanced,
-    Aggressive,
-    Passive,
-}
-
-impl From<RivalStyleArg> for RivalStyle {
-    fn from(arg: RivalStyleArg) -> Self {
-        match arg {
-            RivalStyleArg::Balanced => RivalStyle::Balanced,
-            RivalStyleArg::Aggressive => RivalStyle::Aggressive,
-            RivalStyleArg::Passive => RivalStyle::Passive,
-        }
-    }
-}
-
 #[tokio::main]
 async fn main() -> Result<()> {
     let _ = color_eyre::install();
@@ -83,21 +78,36 @@ async fn main() -> Result<()> {
 }
 
 async fn run_cli(cli: Cli) -> Result<()> {
+    if rival_strategy::resolve_strategy(&cli.rival_style).is_none() {
+        anyhow::bail!(
+            "unknown rival style '{}' (available: {})",
+            cli.rival_style,
+            rival_strategy::strategies().join(", ")
+        );
+    }
+
     let config = TrainerConfig {
         hands: cli.hands,
         mc_samples: cli.mc_samples,
+        exact_equity_threshold: cli.exact_equity_threshold,
         seed: cli.seed,
-        rival_style: cli.rival_style.clone().into(),
+        rival_style: RivalStyle::default(),
+        rival_strategy: Some(cli.rival_style.clone()),
         no_color: cli.no_color,
     };
     let mut trainer = Trainer::new(config);
     if cli.auto {
         let summary = trainer.autoplay_best()?;
         trainer.print_summary(&summary);
-        Ok(())
     } else {
-        trainer.run()
+        trainer.run()?;
     }
+
+    if let Some(path) = cli.export {
+        std::fs::write(&path, trainer.export_json()?)?;
+    }
+
+    Ok(())
 }
 
 async fn run_server(addr: String) -> Result<()> {
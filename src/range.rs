@@ -0,0 +1,344 @@
+//! Weighted starting-hand ranges, parsed from standard poker range notation, used in
+//! place of a single known villain hand so equity can be computed against every hand a
+//! rival could plausibly hold rather than a uniformly random one.
+
+use std::collections::HashMap;
+
+use rand::Rng;
+use rand::distributions::{Distribution, Uniform};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::{Card, Rank, Suit, standard_deck};
+
+/// One concrete two-card holding in a range, with a mixing weight in `[0, 1]`. Every
+/// combo `HandRange::parse` produces is weighted `1.0` (full membership); the field
+/// exists so a future mixed-frequency range could scale combos down without changing
+/// the representation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RangeCombo {
+    pub cards: (Card, Card),
+    pub weight: f32,
+}
+
+/// A weighted set of starting hands, either parsed from range notation (see `parse`) or
+/// the unconstrained `full()` range (any two cards, equally likely).
+#[derive(Debug, Clone)]
+pub struct HandRange {
+    combos: Vec<RangeCombo>,
+    notation: String,
+}
+
+/// Read-only summary of a `HandRange`, attached to a `NodeSnapshot` so a viewer can see
+/// what the rival is assumed to hold without serializing all 1326 possible combos.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RangeSummary {
+    pub notation: String,
+    pub combo_count: usize,
+}
+
+impl HandRange {
+    /// Parses comma-separated range notation into the expanded combo space. Recognises
+    /// pair classes (`99`, `99+`, `88-55`), suited/offsuit classes (`AKs`, `AKo`,
+    /// `A2s+`, `T9s-76s`), and the unsuffixed "both" class (`AK` = `AKs` + `AKo`).
+    /// Combos named by more than one token are deduplicated. Ranks are single
+    /// characters only (`T` for ten), matching `Rank::short_label`.
+    pub fn parse(notation: &str) -> Result<Self, String> {
+        let mut combos: HashMap<(Card, Card), f32> = HashMap::new();
+        for token in notation.split(',') {
+            let token = token.trim();
+            if token.is_empty() {
+                continue;
+            }
+            for class in expand_token(token)? {
+                for cards in class.combos() {
+                    combos.entry(cards).or_insert(1.0);
+                }
+            }
+        }
+        Ok(Self::from_combos(combos, notation.to_string()))
+    }
+
+    /// Every two-card combo, unweighted (equivalent to "any two cards").
+    pub fn full() -> Self {
+        let mut combos: HashMap<(Card, Card), f32> = HashMap::new();
+        for class in Rank::ALL.iter().flat_map(|&high| {
+            Rank::ALL
+                .iter()
+                .filter(move |&&low| low.value() <= high.value())
+                .map(move |&low| {
+                    if low == high {
+                        RangeClass::Pair(high)
+                    } else {
+                        RangeClass::Both(high, low)
+                    }
+                })
+        }) {
+            for cards in class.combos() {
+                combos.entry(cards).or_insert(1.0);
+            }
+        }
+        Self::from_combos(combos, "any two cards".to_string())
+    }
+
+    fn from_combos(combos: HashMap<(Card, Card), f32>, notation: String) -> Self {
+        let mut combos: Vec<RangeCombo> = combos
+            .into_iter()
+            .map(|(cards, weight)| RangeCombo { cards, weight })
+            .collect();
+        combos.sort_by_key(|c| (c.cards.0.rank_value(), c.cards.1.rank_value()));
+        Self { combos, notation }
+    }
+
+    /// The notation this range was built from (or `"any two cards"` for `full()`).
+    pub fn notation(&self) -> &str {
+        &self.notation
+    }
+
+    /// Every combo in the range, blockers included.
+    pub fn combos(&self) -> &[RangeCombo] {
+        &self.combos
+    }
+
+    /// Combos that don't share a card with anything in `dead` (hero's hole cards, the
+    /// board, or both).
+    pub fn combos_excluding(&self, dead: &[Card]) -> Vec<RangeCombo> {
+        self.combos
+            .iter()
+            .copied()
+            .filter(|combo| !dead.contains(&combo.cards.0) && !dead.contains(&combo.cards.1))
+            .collect()
+    }
+
+    /// Draws one villain combo from `live`, weighted by combo weight. `live` is assumed
+    /// non-empty and already filtered for card removal (see `combos_excluding`).
+    pub fn sample_from<R: Rng + ?Sized>(live: &[RangeCombo], rng: &mut R) -> (Card, Card) {
+        let total: f32 = live.iter().map(|c| c.weight).sum();
+        if total <= 0.0 {
+            return live[0].cards;
+        }
+        let uniform = Uniform::new(0.0f32, total);
+        let mut pick = uniform.sample(rng);
+        for combo in live {
+            if pick < combo.weight {
+                return combo.cards;
+            }
+            pick -= combo.weight;
+        }
+        live.last().expect("live is non-empty").cards
+    }
+
+    /// Draws one villain combo consistent with `dead`, or a uniformly random pair of
+    /// remaining cards if every combo in the range collides with `dead` (an
+    /// over-narrowed range against this particular board, not a hard error).
+    pub fn sample<R: Rng + ?Sized>(&self, dead: &[Card], rng: &mut R) -> (Card, Card) {
+        let live = self.combos_excluding(dead);
+        if !live.is_empty() {
+            return Self::sample_from(&live, rng);
+        }
+        let mut remaining: Vec<Card> = standard_deck()
+            .into_iter()
+            .filter(|c| !dead.contains(c))
+            .collect();
+        remaining.shuffle(rng);
+        (remaining[0], remaining[1])
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RangeClass {
+    Pair(Rank),
+    Suited(Rank, Rank),
+    Offsuit(Rank, Rank),
+    Both(Rank, Rank),
+}
+
+impl RangeClass {
+    fn top_rank(self) -> Rank {
+        match self {
+            RangeClass::Pair(rank) => rank,
+            RangeClass::Suited(high, _) | RangeClass::Offsuit(high, _) | RangeClass::Both(high, _) => high,
+        }
+    }
+
+    fn combos(self) -> Vec<(Card, Card)> {
+        match self {
+            RangeClass::Pair(rank) => {
+                let mut out = Vec::with_capacity(6);
+                for i in 0..Suit::ALL.len() {
+                    for j in (i + 1)..Suit::ALL.len() {
+                        out.push((Card::new(rank, Suit::ALL[i]), Card::new(rank, Suit::ALL[j])));
+                    }
+                }
+                out
+            }
+            RangeClass::Suited(high, low) => Suit::ALL
+                .iter()
+                .map(|&suit| (Card::new(high, suit), Card::new(low, suit)))
+                .collect(),
+            RangeClass::Offsuit(high, low) => {
+                let mut out = Vec::with_capacity(12);
+                for &hs in &Suit::ALL {
+                    for &ls in &Suit::ALL {
+                        if hs != ls {
+                            out.push((Card::new(high, hs), Card::new(low, ls)));
+                        }
+                    }
+                }
+                out
+            }
+            RangeClass::Both(high, low) => {
+                let mut out = RangeClass::Suited(high, low).combos();
+                out.extend(RangeClass::Offsuit(high, low).combos());
+                out
+            }
+        }
+    }
+}
+
+fn order_ranks(a: Rank, b: Rank) -> (Rank, Rank) {
+    if a.value() >= b.value() { (a, b) } else { (b, a) }
+}
+
+fn parse_rank(c: char) -> Result<Rank, String> {
+    c.to_string().parse::<Rank>()
+}
+
+fn rank_from_value(value: u8) -> Option<Rank> {
+    Rank::ALL.iter().copied().find(|r| r.value() == value)
+}
+
+/// Parses one class token (no `+`/`-` modifiers): `"99"` (pair), `"AK"` (both suited and
+/// offsuit), or `"AKs"`/`"AKo"` (one or the other).
+fn parse_class(token: &str) -> Result<RangeClass, String> {
+    let chars: Vec<char> = token.chars().collect();
+    match chars.len() {
+        2 => {
+            let r1 = parse_rank(chars[0])?;
+            let r2 = parse_rank(chars[1])?;
+            if r1 == r2 {
+                Ok(RangeClass::Pair(r1))
+            } else {
+                let (high, low) = order_ranks(r1, r2);
+                Ok(RangeClass::Both(high, low))
+            }
+        }
+        3 => {
+            let r1 = parse_rank(chars[0])?;
+            let r2 = parse_rank(chars[1])?;
+            if r1 == r2 {
+                return Err(format!("pair class '{token}' can't take a suited/offsuit suffix"));
+            }
+            let (high, low) = order_ranks(r1, r2);
+            match chars[2].to_ascii_lowercase() {
+                's' => Ok(RangeClass::Suited(high, low)),
+                'o' => Ok(RangeClass::Offsuit(high, low)),
+                other => Err(format!("unknown suffix '{other}' in range token '{token}'")),
+            }
+        }
+        _ => Err(format!("unrecognised range token '{token}'")),
+    }
+}
+
+/// Expands a `+` ("and better") class into every class of the same shape between the
+/// given rank and one below the top rank (pairs go all the way to aces).
+fn expand_plus(class: RangeClass) -> Vec<RangeClass> {
+    match class {
+        RangeClass::Pair(low) => Rank::ALL
+            .iter()
+            .copied()
+            .filter(|r| r.value() >= low.value())
+            .map(RangeClass::Pair)
+            .collect(),
+        RangeClass::Suited(high, low) => Rank::ALL
+            .iter()
+            .copied()
+            .filter(|r| r.value() >= low.value() && r.value() < high.value())
+            .map(|r| RangeClass::Suited(high, r))
+            .collect(),
+        RangeClass::Offsuit(high, low) => Rank::ALL
+            .iter()
+            .copied()
+            .filter(|r| r.value() >= low.value() && r.value() < high.value())
+            .map(|r| RangeClass::Offsuit(high, r))
+            .collect(),
+        RangeClass::Both(high, low) => Rank::ALL
+            .iter()
+            .copied()
+            .filter(|r| r.value() >= low.value() && r.value() < high.value())
+            .map(|r| RangeClass::Both(high, r))
+            .collect(),
+    }
+}
+
+/// Expands a `high-low` connector-style range (e.g. `T9s-76s`) into every class of the
+/// same shape and rank gap in between. Accepts either order of the two endpoints.
+fn expand_dash(left: RangeClass, right: RangeClass, token: &str) -> Result<Vec<RangeClass>, String> {
+    let (high, low) = if left.top_rank().value() >= right.top_rank().value() {
+        (left, right)
+    } else {
+        (right, left)
+    };
+
+    match (high, low) {
+        (RangeClass::Pair(hi), RangeClass::Pair(lo)) => Ok(Rank::ALL
+            .iter()
+            .copied()
+            .filter(|r| r.value() >= lo.value() && r.value() <= hi.value())
+            .map(RangeClass::Pair)
+            .collect()),
+        (RangeClass::Suited(hh, hl), RangeClass::Suited(lh, ll)) => {
+            expand_dash_gapped(hh, hl, lh, ll, RangeClass::Suited, token)
+        }
+        (RangeClass::Offsuit(hh, hl), RangeClass::Offsuit(lh, ll)) => {
+            expand_dash_gapped(hh, hl, lh, ll, RangeClass::Offsuit, token)
+        }
+        (RangeClass::Both(hh, hl), RangeClass::Both(lh, ll)) => {
+            expand_dash_gapped(hh, hl, lh, ll, RangeClass::Both, token)
+        }
+        _ => Err(format!("range '{token}' mixes incompatible class types")),
+    }
+}
+
+fn expand_dash_gapped(
+    high_high: Rank,
+    high_low: Rank,
+    low_high: Rank,
+    low_low: Rank,
+    build: fn(Rank, Rank) -> RangeClass,
+    token: &str,
+) -> Result<Vec<RangeClass>, String> {
+    let gap = high_high.value() as i16 - high_low.value() as i16;
+    if gap != low_high.value() as i16 - low_low.value() as i16 {
+        return Err(format!("range '{token}' doesn't preserve a constant gap between endpoints"));
+    }
+
+    let mut out = Vec::new();
+    let mut hi = high_high.value();
+    loop {
+        let lo_value = hi as i16 - gap;
+        if lo_value < Rank::Two.value() as i16 {
+            break;
+        }
+        let hi_rank = rank_from_value(hi).ok_or_else(|| format!("bad rank in '{token}'"))?;
+        let lo_rank = rank_from_value(lo_value as u8).ok_or_else(|| format!("bad rank in '{token}'"))?;
+        out.push(build(hi_rank, lo_rank));
+        if hi <= low_high.value() {
+            break;
+        }
+        hi -= 1;
+    }
+    Ok(out)
+}
+
+/// Expands one comma-separated token (a class, a `+` class, or a `high-low` range) into
+/// the classes it names.
+fn expand_token(token: &str) -> Result<Vec<RangeClass>, String> {
+    if let Some(base) = token.strip_suffix('+') {
+        return Ok(expand_plus(parse_class(base)?));
+    }
+    if let Some((left, right)) = token.split_once('-') {
+        return expand_dash(parse_class(left.trim())?, parse_class(right.trim())?, token);
+    }
+    Ok(vec![parse_class(token)?])
+}
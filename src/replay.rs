@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::game::{ActionOption, HeroAction, Street};
+use crate::rival::RivalStyle;
+
+/// Current version of the replay/export schema below. Bump this whenever
+/// `SessionReplay` or `ReplayExport`'s shape changes, so old exports can be rejected
+/// instead of silently misparsed by a newer trainer.
+pub const REPLAY_SCHEMA_VERSION: u32 = 3;
+
+/// One decision point within a hand: the node hero faced, the options offered, and the
+/// action actually taken. Streets are ordered so a viewer can step through the hand as
+/// the board is revealed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReplayEntry {
+    pub street: Street,
+    pub pot_bb: f32,
+    pub hero_invested_bb: f32,
+    pub villain_invested_bb: f32,
+    pub board: Vec<String>,
+    pub options: Vec<ActionOption>,
+    pub action_taken: HeroAction,
+}
+
+/// The full decision trace for one completed hand, including villain's cards (only
+/// meaningful once the hand is over) and its final result.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct HandReplay {
+    pub hand_index: u32,
+    pub hero_cards: Vec<String>,
+    pub villain_cards: Vec<String>,
+    pub entries: Vec<ReplayEntry>,
+    pub profit_bb: f32,
+    pub ev_loss_bb: f32,
+}
+
+/// A replayable record of a session: every hand played, in order, with enough detail
+/// (hero cards, options offered, action chosen) for a third-party viewer to step through
+/// and re-study each decision. Villain's actions have no entries of their own; they show
+/// only through the pot and invested amounts of the next entry and the hand's profit.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SessionReplay {
+    pub schema_version: u32,
+    pub session_id: Uuid,
+    pub hands: Vec<HandReplay>,
+}
+
+/// A `SessionReplay` plus the config needed to recreate the session deterministically
+/// (same dealt cards, same rival decisions) from scratch, rather than only being able
+/// to view what already happened.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ReplayExport {
+    pub schema_version: u32,
+    pub seed: u64,
+    pub hands: u32,
+    pub mc_samples: u32,
+    /// Largest exact-enumeration space `equity::resolve_equity_vs_range` was allowed to walk;
+    /// see `SessionConfig::exact_equity_threshold`. Needed for deterministic replay,
+    /// since a different threshold can pick a different (exact vs Monte Carlo) equity
+    /// path and so consume a different number of RNG draws.
+    pub exact_equity_threshold: u64,
+    pub rival_style: RivalStyle,
+    /// Registered `RivalStrategy` name the session actually played against, if one was
+    /// selected (see `rival_strategy::strategies`); `None` when `rival_style` alone
+    /// determined the opponent.
+    pub rival_strategy: Option<String>,
+    pub replay: SessionReplay,
+}
@@ -3,8 +3,9 @@ use rand::distributions::{Distribution, Uniform};
 use serde::{Deserialize, Serialize};
 
 use crate::cards::Card;
+use crate::range::HandRange;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum RivalStyle {
     #[default]
@@ -16,11 +17,18 @@ pub enum RivalStyle {
 #[derive(Debug, Clone, Copy)]
 pub struct RivalProfile {
     pub name: &'static str,
+    pub preflop_fold_to_open: f32,
     pub preflop_fold_to_three_bet: f32,
     pub flop_continuation_bet: f32,
     pub turn_barrel_frequency: f32,
     pub river_probe_frequency: f32,
     pub aggression: f32,
+    /// Range notation (see `HandRange::parse`) for what this style opens/defends with
+    /// before any postflop action has narrowed it.
+    opening_range: &'static str,
+    /// Narrower range notation for postflop streets, reflecting that the hands which
+    /// didn't fold preflop skew stronger than the full opening range.
+    continuing_range: &'static str,
 }
 
 impl RivalProfile {
@@ -28,36 +36,64 @@ impl RivalProfile {
         match style {
             RivalStyle::Balanced => Self {
                 name: "balanced",
+                preflop_fold_to_open: 0.55,
                 preflop_fold_to_three_bet: 0.48,
                 flop_continuation_bet: 0.62,
                 turn_barrel_frequency: 0.52,
                 river_probe_frequency: 0.33,
                 aggression: 0.5,
+                opening_range: "22+, A2s+, K8s+, Q9s+, J9s+, T8s+, 97s+, 86s+, 75s+, A8o+, KTo+, QTo+, JTo",
+                continuing_range: "55+, A9s+, KTs+, QTs+, JTs, T9s, A9o+, KQo",
             },
             RivalStyle::Aggressive => Self {
                 name: "aggressive",
+                preflop_fold_to_open: 0.45,
                 preflop_fold_to_three_bet: 0.38,
                 flop_continuation_bet: 0.71,
                 turn_barrel_frequency: 0.64,
                 river_probe_frequency: 0.47,
                 aggression: 0.68,
+                opening_range: "22+, A2s+, K2s+, Q6s+, J7s+, T7s+, 96s+, 85s+, 74s+, 63s+, A2o+, K8o+, Q9o+, J9o+, T9o",
+                continuing_range: "33+, A5s+, K9s+, Q9s+, J9s+, T8s+, 98s, A8o+, KTo+, QTo+, JTo",
             },
             RivalStyle::Passive => Self {
                 name: "passive",
+                preflop_fold_to_open: 0.65,
                 preflop_fold_to_three_bet: 0.57,
                 flop_continuation_bet: 0.44,
                 turn_barrel_frequency: 0.36,
                 river_probe_frequency: 0.21,
                 aggression: 0.32,
+                opening_range: "55+, ATs+, KTs+, QTs+, JTs, AJo+, KQo",
+                continuing_range: "77+, AQs+, KQs, AKo",
             },
         }
     }
 
+    /// Default range of starting hands this style would have opened or defended with
+    /// before any postflop action has narrowed it (used preflop).
+    pub fn opening_range(&self) -> HandRange {
+        HandRange::parse(self.opening_range).expect("built-in opening range notation is valid")
+    }
+
+    /// Narrower default range for postflop streets, reflecting that hands which didn't
+    /// fold preflop skew stronger than the full opening range.
+    pub fn continuing_range(&self) -> HandRange {
+        HandRange::parse(self.continuing_range).expect("built-in continuing range notation is valid")
+    }
+
     pub fn fold_to_three_bet(&self, hero_strength: f32) -> f32 {
         let adjustment = (0.5 - hero_strength) * 0.35;
         (self.preflop_fold_to_three_bet + adjustment).clamp(0.05, 0.85)
     }
 
+    /// Probability the rival folds the big blind to hero's opening raise from the
+    /// button, given hero's hand-strength hint.
+    pub fn fold_to_open_raise(&self, hero_strength: f32) -> f32 {
+        let adjustment = (0.5 - hero_strength) * 0.3;
+        (self.preflop_fold_to_open + adjustment).clamp(0.05, 0.9)
+    }
+
     pub fn continuation_bet_flop(&self) -> f32 {
         self.flop_continuation_bet
     }
@@ -89,20 +125,28 @@ impl RivalProfile {
     }
 
     pub fn hand_strength_hint(&self, hero_cards: &[Card; 2]) -> f32 {
-        let ranks: [u8; 2] = [hero_cards[0].rank_value(), hero_cards[1].rank_value()];
-        let connectors = (ranks[0] as i8 - ranks[1] as i8).abs() <= 1;
-        let pair = hero_cards[0].rank == hero_cards[1].rank;
-        let suited = hero_cards[0].suit == hero_cards[1].suit;
-        let base = (ranks[0] + ranks[1]) as f32 / 28.0; // normalise between 0 and ~1
-        let mut strength = base;
-        if pair {
-            strength += 0.25;
-        } else if connectors {
-            strength += 0.08;
-        }
-        if suited {
-            strength += 0.05;
-        }
-        strength.clamp(0.0, 1.0)
+        hand_strength_hint(hero_cards)
+    }
+}
+
+/// Heuristic hand-strength estimate in `[0, 1]` from hero's two hole cards alone (no
+/// board, no Monte Carlo run). Doesn't depend on any particular rival's style, so it's
+/// a free function rather than a `RivalProfile` method; `rival_strategy::DecisionContext`
+/// carries its result as `hero_strength` for preflop decisions.
+pub fn hand_strength_hint(hero_cards: &[Card; 2]) -> f32 {
+    let ranks: [u8; 2] = [hero_cards[0].rank_value(), hero_cards[1].rank_value()];
+    let connectors = (ranks[0] as i8 - ranks[1] as i8).abs() <= 1;
+    let pair = hero_cards[0].rank == hero_cards[1].rank;
+    let suited = hero_cards[0].suit == hero_cards[1].suit;
+    let base = (ranks[0] + ranks[1]) as f32 / 28.0; // normalise between 0 and ~1
+    let mut strength = base;
+    if pair {
+        strength += 0.25;
+    } else if connectors {
+        strength += 0.08;
+    }
+    if suited {
+        strength += 0.05;
     }
+    strength.clamp(0.0, 1.0)
 }
@@ -0,0 +1,435 @@
+//! Pluggable rival decision-making, keyed off a `DecisionContext` rather than hardcoded
+//! to the three `RivalStyle` presets. `Session` asks a `Box<dyn RivalStrategy>` for an
+//! action distribution (for EV-preview math) or samples a concrete `RivalDecision`
+//! (when actually executing villain's turn), instead of branching on raw floats itself.
+
+use std::sync::Mutex;
+
+use rand::RngCore;
+use rand::distributions::{Distribution, Uniform};
+
+use crate::game::{ActionOption, HeroAction, HeroActionKind, NodeSnapshot, Position, Street};
+use crate::range::HandRange;
+use crate::rival::{RivalProfile, RivalStyle};
+use crate::strategy::Strategy;
+
+/// Everything a rival strategy needs to decide its next move, short of villain's own
+/// hidden cards (strategies never see those, same as the old `RivalProfile` formulas).
+#[derive(Debug, Clone, Copy)]
+pub struct DecisionContext {
+    pub street: Street,
+    pub hero_position: Position,
+    /// `Some(amount)` when villain is the one facing a bet/raise/3-bet of `amount`;
+    /// `None` when villain has the initiative (hero just checked, or it's preflop and
+    /// hero hasn't acted yet).
+    pub facing_bet_bb: Option<f32>,
+    pub pot_bb: f32,
+    pub hero_invested_bb: f32,
+    pub villain_invested_bb: f32,
+    pub effective_stack_bb: f32,
+    /// Number of raises already made on the current street (capped at one upstream).
+    pub street_raises: u8,
+    /// Preflop: `rival::hand_strength_hint` for hero's hole cards. Postflop: hero's
+    /// equity against the rival's range (`equity::resolve_equity_vs_range`). Either way,
+    /// the rival's best read on how strong hero is.
+    pub hero_strength: f32,
+    /// Hero's average EV given up per hand so far this session (0 if hero has been
+    /// playing close to best-EV). Lets a strategy exploit a hero who's deviating a lot.
+    pub hero_ev_deviation_bb: f32,
+}
+
+/// What a rival strategy decided to do. `Session` maps this onto the same chip
+/// movements it already applies for any other villain action.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RivalDecision {
+    Fold,
+    Call,
+    CheckBehind,
+    Lead { size_bb: f32 },
+    Raise { size_bb: f32 },
+}
+
+/// Probabilities driving `RivalStrategy::decide`. Only the fields relevant to the
+/// current `DecisionContext` are meaningful: `fold`/`raise` when `facing_bet_bb` is
+/// `Some`, `lead` when it's `None`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RivalDistribution {
+    pub fold: f32,
+    pub raise: f32,
+    pub lead: f32,
+}
+
+/// A pluggable source of rival behaviour. Built-in strategies wrap `RivalProfile`; see
+/// `strategies()` for the full registry of names the CLI and web API accept.
+pub trait RivalStrategy: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    /// The probability of folding/raising/leading implied by `ctx`.
+    fn action_distribution(&self, ctx: &DecisionContext) -> RivalDistribution;
+
+    /// The range of starting hands this rival plausibly holds on `street`, used in
+    /// place of a uniformly random hand when computing hero's equity. Defaults to any
+    /// two cards, equally likely, for strategies that don't model a narrower range.
+    fn range(&self, street: Street) -> HandRange {
+        let _ = street;
+        HandRange::full()
+    }
+
+    /// Sizes a lead bet when leading into `ctx.pot_bb`.
+    fn lead_size_bb(&self, ctx: &DecisionContext) -> f32 {
+        let heuristic_multiplier = match ctx.street {
+            Street::Flop => 0.5,
+            Street::Turn => 0.6,
+            _ => 0.75,
+        };
+        (ctx.pot_bb * heuristic_multiplier)
+            .max(0.5)
+            .min(ctx.effective_stack_bb.max(0.5))
+    }
+
+    /// Sizes a raise over `ctx.facing_bet_bb`, as the incremental chips villain adds.
+    fn raise_size_bb(&self, ctx: &DecisionContext) -> f32 {
+        let facing = ctx.facing_bet_bb.unwrap_or(0.0);
+        let target_total =
+            (ctx.hero_invested_bb * 2.2).max(ctx.villain_invested_bb + facing + 0.5);
+        (target_total - ctx.villain_invested_bb)
+            .max(facing + 0.5)
+            .min(ctx.effective_stack_bb.max(0.5))
+    }
+
+    /// Samples a concrete decision from `action_distribution(ctx)`. Strategies
+    /// typically don't need to override this - override the probability/sizing
+    /// methods above instead.
+    fn decide(&self, ctx: &DecisionContext, rng: &mut dyn RngCore) -> RivalDecision {
+        let distribution = self.action_distribution(ctx);
+        let uniform = Uniform::new_inclusive(0.0f32, 1.0f32);
+        let roll = uniform.sample(rng);
+
+        match ctx.facing_bet_bb {
+            Some(_) => {
+                if roll < distribution.fold {
+                    RivalDecision::Fold
+                } else if ctx.street_raises == 0 && roll < distribution.fold + distribution.raise {
+                    RivalDecision::Raise {
+                        size_bb: self.raise_size_bb(ctx),
+                    }
+                } else {
+                    RivalDecision::Call
+                }
+            }
+            None => {
+                if roll < distribution.lead {
+                    RivalDecision::Lead {
+                        size_bb: self.lead_size_bb(ctx),
+                    }
+                } else {
+                    RivalDecision::CheckBehind
+                }
+            }
+        }
+    }
+}
+
+/// Fold probability for a postflop bet of `size_bb` into a pot of `pot_bb`. Scales with
+/// street, rival aggression, hero's apparent strength, and how large the bet is
+/// relative to the pot (bigger bets fold out more hands), so a solver searching over
+/// `size_bb` sees a genuine interior optimum rather than a flat or monotonic curve.
+fn postflop_fold_probability(
+    profile: &RivalProfile,
+    hero_strength: f32,
+    street: Street,
+    size_bb: f32,
+    pot_bb: f32,
+) -> f32 {
+    let (base, aggression_metric) = match street {
+        Street::Flop => (0.4, profile.continuation_bet_flop()),
+        Street::Turn => (0.35, profile.barrel_turn()),
+        Street::River => (0.3, profile.probe_river()),
+        _ => (0.45, 0.5),
+    };
+
+    let aggression_adjust = (0.5 - aggression_metric) * 0.3;
+    let strength_adjust = (0.5 - hero_strength) * 0.35;
+    let size_ratio = (size_bb / pot_bb.max(0.01)).clamp(0.1, 3.0);
+    let size_adjust = (size_ratio - 0.66) * 0.15;
+    let raw = base + aggression_adjust + strength_adjust + size_adjust;
+    raw.clamp(0.05, 0.9)
+}
+
+/// Probability the rival raises rather than folding or calling, driven by aggression
+/// and how far ahead hero appears to be (bluff-raises get more likely as hero's
+/// apparent edge grows, mirroring real check-raise/float behaviour).
+fn postflop_raise_probability(profile: &RivalProfile, hero_strength: f32) -> f32 {
+    ((profile.bluff_tendency() - 0.35) * 0.4 + (hero_strength - 0.5) * 0.2).clamp(0.0, 0.3)
+}
+
+/// The decision distribution a fixed `RivalProfile` preset implies for `ctx`, shared by
+/// `ProfileStrategy` and as the base `TrackerStrategy` adapts away from.
+fn profile_distribution(profile: &RivalProfile, ctx: &DecisionContext) -> RivalDistribution {
+    match ctx.street {
+        Street::Preflop => {
+            let fold = match ctx.hero_position {
+                Position::BigBlind => profile.fold_to_three_bet(ctx.hero_strength),
+                Position::Button => profile.fold_to_open_raise(ctx.hero_strength),
+            };
+            RivalDistribution {
+                fold,
+                raise: 0.0,
+                lead: 0.0,
+            }
+        }
+        Street::Flop | Street::Turn | Street::River => match ctx.facing_bet_bb {
+            None => {
+                let lead = match ctx.street {
+                    Street::Flop => profile.continuation_bet_flop(),
+                    Street::Turn => profile.barrel_turn(),
+                    _ => profile.probe_river(),
+                };
+                RivalDistribution {
+                    fold: 0.0,
+                    raise: 0.0,
+                    lead,
+                }
+            }
+            Some(size_bb) => {
+                let fold =
+                    postflop_fold_probability(profile, ctx.hero_strength, ctx.street, size_bb, ctx.pot_bb);
+                let raise = postflop_raise_probability(profile, ctx.hero_strength);
+                RivalDistribution {
+                    fold,
+                    raise,
+                    lead: 0.0,
+                }
+            }
+        },
+        Street::Showdown | Street::Terminal => RivalDistribution::default(),
+    }
+}
+
+/// Wraps one of the three fixed `RivalStyle` presets as a `RivalStrategy`.
+pub struct ProfileStrategy {
+    profile: RivalProfile,
+}
+
+impl ProfileStrategy {
+    pub fn new(style: RivalStyle) -> Self {
+        Self {
+            profile: RivalProfile::resolve(style),
+        }
+    }
+}
+
+impl RivalStrategy for ProfileStrategy {
+    fn name(&self) -> &'static str {
+        self.profile.describe()
+    }
+
+    fn action_distribution(&self, ctx: &DecisionContext) -> RivalDistribution {
+        profile_distribution(&self.profile, ctx)
+    }
+
+    fn range(&self, street: Street) -> HandRange {
+        match street {
+            Street::Preflop => self.profile.opening_range(),
+            _ => self.profile.continuing_range(),
+        }
+    }
+}
+
+/// A context-aware strategy that starts from a fixed preset but shades its fold/bluff
+/// frequencies based on how much EV hero has left on the table so far this session:
+/// the more hero deviates from best-EV play, the more the tracker presses, reading
+/// hero as exploitable rather than sticking to the static preset.
+pub struct TrackerStrategy {
+    base: RivalProfile,
+}
+
+impl TrackerStrategy {
+    pub fn new(base: RivalStyle) -> Self {
+        Self {
+            base: RivalProfile::resolve(base),
+        }
+    }
+
+    /// How far to shade aggression given `ctx.hero_ev_deviation_bb`: giving up half a
+    /// big blind a hand on average already reads as exploitable.
+    fn exploit_shift(&self, ctx: &DecisionContext) -> f32 {
+        (ctx.hero_ev_deviation_bb / 2.0).clamp(0.0, 0.2)
+    }
+}
+
+impl RivalStrategy for TrackerStrategy {
+    fn name(&self) -> &'static str {
+        "tracker"
+    }
+
+    fn action_distribution(&self, ctx: &DecisionContext) -> RivalDistribution {
+        let mut distribution = profile_distribution(&self.base, ctx);
+        let shift = self.exploit_shift(ctx);
+        distribution.fold = (distribution.fold - shift).clamp(0.05, 0.95);
+        distribution.raise = (distribution.raise + shift * 0.5).clamp(0.0, 0.5);
+        distribution.lead = (distribution.lead + shift).clamp(0.0, 0.95);
+        distribution
+    }
+
+    fn range(&self, street: Street) -> HandRange {
+        match street {
+            Street::Preflop => self.base.opening_range(),
+            _ => self.base.continuing_range(),
+        }
+    }
+}
+
+/// Names accepted by `resolve_strategy`, for the CLI/web layer to list or validate
+/// against without hardcoding a parallel enum of its own.
+pub fn strategies() -> &'static [&'static str] {
+    &["balanced", "aggressive", "passive", "tracker"]
+}
+
+/// Looks up a registered strategy by name (see `strategies()`), or `None` if `name`
+/// isn't registered.
+pub fn resolve_strategy(name: &str) -> Option<Box<dyn RivalStrategy>> {
+    match name {
+        "balanced" => Some(Box::new(ProfileStrategy::new(RivalStyle::Balanced))),
+        "aggressive" => Some(Box::new(ProfileStrategy::new(RivalStyle::Aggressive))),
+        "passive" => Some(Box::new(ProfileStrategy::new(RivalStyle::Passive))),
+        "tracker" => Some(Box::new(TrackerStrategy::new(RivalStyle::Balanced))),
+        _ => None,
+    }
+}
+
+/// Adapts a hero-seat `Strategy` to play the villain seat, so `Simulator::run_matchup`
+/// can pit two `Strategy` implementations against each other through the same
+/// `Session` machinery that drives a human or `BestEvStrategy` hero against a built-in
+/// rival. `action_distribution` (used by hero's own EV-preview/bet-size search, which
+/// needs a stable probability surface to search over) falls back to a fixed balanced
+/// profile; only `decide` (the actual executed decision) consults the wrapped
+/// `Strategy`, via a synthetic `NodeSnapshot` built from the `DecisionContext`.
+///
+/// This is necessarily a lossy adapter: a `DecisionContext` doesn't carry hole cards,
+/// board cards, or villain's own EV numbers, so the synthetic snapshot's `action_options`
+/// all report `ev_delta_bb: 0.0` rather than a real EV estimate for villain's seat.
+/// `BestEvStrategy` degrades to "prefer the most aggressive legal option" under this
+/// adapter (ties break toward the last option, which is always the most aggressive one
+/// offered); `RandomStrategy` is unaffected, since it never looks at `ev_delta_bb`.
+pub struct StrategyRival<S> {
+    baseline: ProfileStrategy,
+    inner: Mutex<S>,
+}
+
+impl<S: Strategy + Send> StrategyRival<S> {
+    pub fn new(strategy: S) -> Self {
+        Self {
+            baseline: ProfileStrategy::new(RivalStyle::Balanced),
+            inner: Mutex::new(strategy),
+        }
+    }
+
+    /// Builds the legal-options snapshot the wrapped `Strategy` decides over: Fold/Call
+    /// (plus Raise, unless the street's single raise is already used) when facing a bet,
+    /// or Check/Bet (standing in for Lead) otherwise.
+    fn context_snapshot(&self, ctx: &DecisionContext) -> NodeSnapshot {
+        let action_options = match ctx.facing_bet_bb {
+            Some(facing_bet_bb) => {
+                let mut options = vec![
+                    ActionOption {
+                        action: HeroAction {
+                            kind: HeroActionKind::Fold,
+                            size_bb: None,
+                        },
+                        ev_delta_bb: 0.0,
+                        description: "Fold".to_string(),
+                    },
+                    ActionOption {
+                        action: HeroAction {
+                            kind: HeroActionKind::Call,
+                            size_bb: Some(facing_bet_bb),
+                        },
+                        ev_delta_bb: 0.0,
+                        description: format!("Call {facing_bet_bb:.1}bb"),
+                    },
+                ];
+                if ctx.street_raises == 0 {
+                    let raise_size = self.raise_size_bb(ctx);
+                    options.push(ActionOption {
+                        action: HeroAction {
+                            kind: HeroActionKind::Raise,
+                            size_bb: Some(raise_size),
+                        },
+                        ev_delta_bb: 0.0,
+                        description: format!("Raise {raise_size:.1}bb"),
+                    });
+                }
+                options
+            }
+            None => {
+                let bet_size = self.lead_size_bb(ctx);
+                vec![
+                    ActionOption {
+                        action: HeroAction {
+                            kind: HeroActionKind::Check,
+                            size_bb: None,
+                        },
+                        ev_delta_bb: 0.0,
+                        description: "Check".to_string(),
+                    },
+                    ActionOption {
+                        action: HeroAction {
+                            kind: HeroActionKind::Bet,
+                            size_bb: Some(bet_size),
+                        },
+                        ev_delta_bb: 0.0,
+                        description: format!("Bet {bet_size:.1}bb"),
+                    },
+                ]
+            }
+        };
+
+        NodeSnapshot {
+            street: ctx.street,
+            hero_position: ctx.hero_position,
+            pot_bb: ctx.pot_bb,
+            effective_stack_bb: ctx.effective_stack_bb,
+            board: Vec::new(),
+            hero_cards: Vec::new(),
+            rival_cards_known: false,
+            action_options,
+            draw: None,
+            rival_range: None,
+        }
+    }
+}
+
+impl<S: Strategy + Send> RivalStrategy for StrategyRival<S> {
+    fn name(&self) -> &'static str {
+        "strategy-matchup"
+    }
+
+    fn action_distribution(&self, ctx: &DecisionContext) -> RivalDistribution {
+        self.baseline.action_distribution(ctx)
+    }
+
+    fn range(&self, street: Street) -> HandRange {
+        self.baseline.range(street)
+    }
+
+    fn decide(&self, ctx: &DecisionContext, rng: &mut dyn RngCore) -> RivalDecision {
+        let snapshot = self.context_snapshot(ctx);
+        let action = {
+            let mut guard = self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            guard.decide(&snapshot, rng)
+        };
+
+        match (ctx.facing_bet_bb, action.kind) {
+            (Some(_), HeroActionKind::Fold) => RivalDecision::Fold,
+            (Some(_), HeroActionKind::Raise) if ctx.street_raises == 0 => RivalDecision::Raise {
+                size_bb: self.raise_size_bb(ctx),
+            },
+            (Some(_), _) => RivalDecision::Call,
+            (None, HeroActionKind::Bet) | (None, HeroActionKind::Raise) => RivalDecision::Lead {
+                size_bb: self.lead_size_bb(ctx),
+            },
+            (None, _) => RivalDecision::CheckBehind,
+        }
+    }
+}
@@ -6,20 +6,36 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::cards::{Card, standard_deck};
-use crate::equity::{best_five_card_hand, compare_strength, monte_carlo_equity};
-use crate::game::{ActionOption, HeroAction, HeroActionKind, NodeSnapshot, Street};
-use crate::rival::{RivalProfile, RivalStyle};
+use crate::equity::{best_five_card_hand, compare_strength, draw_summary, resolve_equity_vs_range};
+use crate::game::{ActionOption, HeroAction, HeroActionKind, NodeSnapshot, Position, Street};
+use crate::range::{HandRange, RangeSummary};
+use crate::replay::{HandReplay, ReplayEntry, SessionReplay};
+use crate::rival::{RivalStyle, hand_strength_hint};
+use crate::rival_strategy::{DecisionContext, ProfileStrategy, RivalDecision, RivalStrategy};
+use crate::solver::anneal_bet_size;
+use crate::strategy::Strategy;
 
 const MAX_STACK_BB: f32 = 100.0;
 const OPEN_SIZES: [f32; 3] = [2.0, 2.5, 3.0];
 const DEFAULT_THREE_BET: f32 = 9.0;
+const SMALL_BLIND_BB: f32 = 0.5;
+const BIG_BLIND_BB: f32 = 1.0;
 
 /// Configuration for a training session.
 #[derive(Debug, Clone)]
 pub struct SessionConfig {
     pub hands: u32,
     pub mc_samples: u32,
+    /// Largest board/villain-combo enumeration `equity::resolve_equity_vs_range` will walk
+    /// exactly; above this it falls back to `mc_samples`-sample Monte Carlo. The
+    /// default (50,000) covers the river and most turns, where the remaining runout is
+    /// small enough that exact enumeration removes Monte Carlo noise entirely.
+    pub exact_equity_threshold: u64,
     pub rival_style: RivalStyle,
+    /// Selects a registered `RivalStrategy` by name (see `rival_strategy::strategies`),
+    /// taking precedence over `rival_style` when set. `rival_style` stays around so
+    /// existing callers that only know the three fixed presets keep working unchanged.
+    pub rival_strategy: Option<String>,
     pub seed: Option<u64>,
 }
 
@@ -28,7 +44,9 @@ impl Default for SessionConfig {
         Self {
             hands: 1,
             mc_samples: 200,
+            exact_equity_threshold: 50_000,
             rival_style: RivalStyle::Balanced,
+            rival_strategy: None,
             seed: None,
         }
     }
@@ -61,9 +79,23 @@ pub struct Session {
     id: Uuid,
     rng: StdRng,
     config: SessionConfig,
-    profile: RivalProfile,
+    rival: Box<dyn RivalStrategy>,
     current_hand: Option<Hand>,
     summary: SessionSummary,
+    replay_hands: Vec<HandReplay>,
+    next_hero_position: Position,
+}
+
+/// Bundles the RNG/rival/equity-budget plumbing every postflop decision point needs, so
+/// the initiative/facing-bet helpers (and their `apply_*` counterparts) don't each grow
+/// their own parallel parameter list - mirrors how `rival_strategy::DecisionContext`
+/// bundles the rival's side of the same kind of plumbing.
+struct PostflopCtx<'a> {
+    rng: &'a mut StdRng,
+    rival: &'a dyn RivalStrategy,
+    samples: u32,
+    max_enumeration: u64,
+    ev_deviation: f32,
 }
 
 #[derive(Debug)]
@@ -79,16 +111,28 @@ struct Hand {
     total_best_ev: f32,
     total_chosen_ev: f32,
     completed: bool,
+    entries: Vec<ReplayEntry>,
+    /// Number of raises already made on the current street; capped at one so the
+    /// betting tree can't recurse indefinitely.
+    street_raises: u8,
+    /// The rival range `compute_options` last computed equity against, kept around
+    /// purely for `node_snapshot`'s display summary.
+    rival_range: Option<RangeSummary>,
 }
 
 #[derive(Debug, Clone, Copy)]
 struct StreetState {
     street: Street,
+    hero_position: Position,
     pot_bb: f32,
     hero_invested_bb: f32,
     villain_invested_bb: f32,
     board_revealed: usize,
     effective_stack_bb: f32,
+    /// `Some(amount)` when hero is on the postflop action facing a bet or raise from
+    /// villain for `amount` more than hero has invested this street; `None` when hero
+    /// has the initiative (first to act, or villain checked behind).
+    facing_bet: Option<f32>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -105,19 +149,54 @@ enum HandProgress {
 
 impl Session {
     pub fn new(config: SessionConfig) -> Self {
+        Self::new_with_position(config, Position::BigBlind)
+    }
+
+    /// Like `new`, but deals the first hand with hero on `hero_position` instead of
+    /// always `Position::BigBlind`. Lets callers that build a fresh, short-lived
+    /// `Session` per hand (e.g. `Simulator::run_with`) still exercise both seats,
+    /// since `next_hero_position`'s rotation only ever applies to a second hand
+    /// within one long-lived session.
+    pub fn new_with_position(config: SessionConfig, hero_position: Position) -> Self {
+        let rival = resolve_rival_strategy(&config);
+        Self::with_rival_and_position(config, rival, hero_position)
+    }
+
+    /// Like `new`, but plays against `rival` directly instead of resolving one from
+    /// `config.rival_strategy`/`rival_style`. Lets callers (e.g. `Simulator::run_matchup`)
+    /// drive the villain seat with an arbitrary `RivalStrategy`, such as a `Strategy`
+    /// wrapped in `rival_strategy::StrategyRival`.
+    pub fn with_rival(config: SessionConfig, rival: Box<dyn RivalStrategy>) -> Self {
+        Self::with_rival_and_position(config, rival, Position::BigBlind)
+    }
+
+    /// Like `with_rival`, but deals the first hand with hero on `hero_position`. See
+    /// `new_with_position` for why a caller would want to vary this.
+    pub fn with_rival_and_position(
+        config: SessionConfig,
+        rival: Box<dyn RivalStrategy>,
+        hero_position: Position,
+    ) -> Self {
         let seed = config.seed.unwrap_or_else(rand::random);
         let mut rng = StdRng::seed_from_u64(seed);
-        let profile = RivalProfile::resolve(config.rival_style);
-        let mut hand = Hand::new(&mut rng);
-        hand.compute_options(&mut rng, profile, config.mc_samples);
+        let mut hand = Hand::new(&mut rng, hero_position);
+        hand.compute_options(
+            &mut rng,
+            rival.as_ref(),
+            config.mc_samples,
+            config.exact_equity_threshold,
+            0.0,
+        );
 
         Self {
             id: Uuid::new_v4(),
             rng,
             config,
-            profile,
+            rival,
             current_hand: Some(hand),
             summary: SessionSummary::default(),
+            replay_hands: Vec::new(),
+            next_hero_position: rotate(hero_position),
         }
     }
 
@@ -125,10 +204,35 @@ impl Session {
         self.id
     }
 
+    /// Returns the replayable decision trace of every hand completed so far.
+    pub fn replay_log(&self) -> SessionReplay {
+        SessionReplay {
+            schema_version: crate::replay::REPLAY_SCHEMA_VERSION,
+            session_id: self.id,
+            hands: self.replay_hands.clone(),
+        }
+    }
+
+    /// Hero's average EV given up per hand so far this session (completed hands plus
+    /// however much of the in-progress hand has unfolded), used as the exploitability
+    /// signal context-aware rival strategies (e.g. `TrackerStrategy`) read from.
+    fn ev_deviation(&self, hand: &Hand) -> f32 {
+        let denom = self.summary.hands_played as f32 + 1.0;
+        (self.summary.total_ev_loss_bb + hand.current_ev_loss()) / denom
+    }
+
     pub fn snapshot(&mut self) -> SessionState {
         if let Some(hand) = &mut self.current_hand {
             if hand.options.is_empty() && !hand.completed {
-                hand.compute_options(&mut self.rng, self.profile, self.config.mc_samples);
+                let ev_deviation =
+                    (self.summary.total_ev_loss_bb + hand.current_ev_loss()) / (self.summary.hands_played as f32 + 1.0);
+                hand.compute_options(
+                    &mut self.rng,
+                    self.rival.as_ref(),
+                    self.config.mc_samples,
+                    self.config.exact_equity_threshold,
+                    ev_deviation,
+                );
             }
             SessionState {
                 session_id: self.id,
@@ -143,12 +247,15 @@ impl Session {
                 hand_index: self.summary.hands_played,
                 node: NodeSnapshot {
                     street: Street::Terminal,
+                    hero_position: self.next_hero_position,
                     pot_bb: 0.0,
                     effective_stack_bb: 0.0,
                     board: vec![],
                     hero_cards: vec![],
                     rival_cards_known: true,
                     action_options: vec![],
+                    draw: None,
+                    rival_range: None,
                 },
                 status: SessionStatus::Completed,
                 summary: self.summary.clone(),
@@ -156,7 +263,38 @@ impl Session {
         }
     }
 
+    /// Consults `strategy` for the current node and applies whatever action it picks.
+    /// No-op once the session has completed.
+    pub fn apply_strategy_action<S: Strategy>(&mut self, strategy: &mut S) {
+        let ev_deviation = match &self.current_hand {
+            Some(hand) => self.ev_deviation(hand),
+            None => return,
+        };
+        let node = match &mut self.current_hand {
+            Some(hand) if !hand.completed => {
+                if hand.options.is_empty() {
+                    hand.compute_options(
+                        &mut self.rng,
+                        self.rival.as_ref(),
+                        self.config.mc_samples,
+                        self.config.exact_equity_threshold,
+                        ev_deviation,
+                    );
+                }
+                hand.node_snapshot()
+            }
+            _ => return,
+        };
+        let action = strategy.decide(&node, &mut self.rng);
+        self.apply_action(&action);
+    }
+
     pub fn apply_action(&mut self, action: &HeroAction) {
+        let ev_deviation = match &self.current_hand {
+            Some(hand) => self.ev_deviation(hand),
+            None => return,
+        };
+
         let hand = match &mut self.current_hand {
             Some(hand) => hand,
             None => return,
@@ -166,19 +304,49 @@ impl Session {
             return;
         }
 
-        match hand.apply_action(action, &mut self.rng, self.profile, self.config.mc_samples) {
+        hand.record_entry(action);
+
+        match hand.apply_action(
+            action,
+            &mut self.rng,
+            self.rival.as_ref(),
+            self.config.mc_samples,
+            self.config.exact_equity_threshold,
+            ev_deviation,
+        ) {
             HandProgress::InProgress => {
-                hand.compute_options(&mut self.rng, self.profile, self.config.mc_samples);
+                hand.compute_options(
+                    &mut self.rng,
+                    self.rival.as_ref(),
+                    self.config.mc_samples,
+                    self.config.exact_equity_threshold,
+                    ev_deviation,
+                );
             }
             HandProgress::Completed(result) => {
                 self.summary.hands_played += 1;
                 self.summary.total_ev_loss_bb += result.ev_loss_bb;
                 self.summary.total_profit_bb += result.profit_bb;
+                self.replay_hands.push(HandReplay {
+                    hand_index: self.summary.hands_played,
+                    hero_cards: hand.hero.iter().map(|c| c.to_string()).collect(),
+                    villain_cards: hand.villain.iter().map(|c| c.to_string()).collect(),
+                    entries: std::mem::take(&mut hand.entries),
+                    profit_bb: result.profit_bb,
+                    ev_loss_bb: result.ev_loss_bb,
+                });
 
                 if self.summary.hands_played < self.config.hands {
-                    let mut next_hand = Hand::new(&mut self.rng);
-                    next_hand.compute_options(&mut self.rng, self.profile, self.config.mc_samples);
+                    let mut next_hand = Hand::new(&mut self.rng, self.next_hero_position);
+                    next_hand.compute_options(
+                        &mut self.rng,
+                        self.rival.as_ref(),
+                        self.config.mc_samples,
+                        self.config.exact_equity_threshold,
+                        0.0,
+                    );
                     self.current_hand = Some(next_hand);
+                    self.next_hero_position = rotate(self.next_hero_position);
                 } else {
                     self.current_hand = None;
                 }
@@ -187,8 +355,18 @@ impl Session {
     }
 }
 
+/// Builds the rival strategy a `Session` plays against: `rival_strategy` (a registered
+/// name) wins if it resolves, otherwise falls back to the preset named by `rival_style`.
+fn resolve_rival_strategy(config: &SessionConfig) -> Box<dyn RivalStrategy> {
+    config
+        .rival_strategy
+        .as_deref()
+        .and_then(crate::rival_strategy::resolve_strategy)
+        .unwrap_or_else(|| Box::new(ProfileStrategy::new(config.rival_style)))
+}
+
 impl Hand {
-    fn new(rng: &mut StdRng) -> Self {
+    fn new(rng: &mut StdRng, hero_position: Position) -> Self {
         let mut deck = standard_deck();
         deck.shuffle(rng);
 
@@ -203,13 +381,27 @@ impl Hand {
         ];
 
         let open_size = *OPEN_SIZES.choose(rng).unwrap_or(&2.5);
-        let state = StreetState {
-            street: Street::Preflop,
-            pot_bb: open_size + 1.0,
-            hero_invested_bb: 1.0,
-            villain_invested_bb: open_size,
-            board_revealed: 0,
-            effective_stack_bb: effective_stack(1.0, open_size),
+        let state = match hero_position {
+            Position::BigBlind => StreetState {
+                street: Street::Preflop,
+                hero_position,
+                pot_bb: open_size + BIG_BLIND_BB,
+                hero_invested_bb: BIG_BLIND_BB,
+                villain_invested_bb: open_size,
+                board_revealed: 0,
+                effective_stack_bb: effective_stack(BIG_BLIND_BB, open_size),
+                facing_bet: None,
+            },
+            Position::Button => StreetState {
+                street: Street::Preflop,
+                hero_position,
+                pot_bb: SMALL_BLIND_BB + BIG_BLIND_BB,
+                hero_invested_bb: SMALL_BLIND_BB,
+                villain_invested_bb: BIG_BLIND_BB,
+                board_revealed: 0,
+                effective_stack_bb: effective_stack(SMALL_BLIND_BB, BIG_BLIND_BB),
+                facing_bet: None,
+            },
         };
 
         Self {
@@ -224,14 +416,77 @@ impl Hand {
             total_best_ev: 0.0,
             total_chosen_ev: 0.0,
             completed: false,
+            entries: Vec::new(),
+            street_raises: 0,
+            rival_range: None,
+        }
+    }
+
+    fn record_entry(&mut self, action: &HeroAction) {
+        self.entries.push(ReplayEntry {
+            street: self.state.street,
+            pot_bb: self.state.pot_bb,
+            hero_invested_bb: self.state.hero_invested_bb,
+            villain_invested_bb: self.state.villain_invested_bb,
+            board: self.visible_board().iter().map(|c| c.to_string()).collect(),
+            options: self.options.clone(),
+            action_taken: action.clone(),
+        });
+    }
+
+    /// Builds the `DecisionContext` rival strategies see for the current street.
+    fn decision_context(
+        &self,
+        facing_bet_bb: Option<f32>,
+        hero_strength: f32,
+        hero_ev_deviation_bb: f32,
+    ) -> DecisionContext {
+        DecisionContext {
+            street: self.state.street,
+            hero_position: self.state.hero_position,
+            facing_bet_bb,
+            pot_bb: self.state.pot_bb,
+            hero_invested_bb: self.state.hero_invested_bb,
+            villain_invested_bb: self.state.villain_invested_bb,
+            effective_stack_bb: self.state.effective_stack_bb,
+            street_raises: self.street_raises,
+            hero_strength,
+            hero_ev_deviation_bb,
         }
     }
 
-    fn compute_options(&mut self, rng: &mut StdRng, profile: RivalProfile, samples: u32) {
+    fn compute_options(
+        &mut self,
+        rng: &mut StdRng,
+        rival: &dyn RivalStrategy,
+        samples: u32,
+        max_enumeration: u64,
+        ev_deviation: f32,
+    ) {
+        let range = rival.range(self.state.street);
+        self.rival_range = Some(RangeSummary {
+            notation: range.notation().to_string(),
+            combo_count: range.combos().len(),
+        });
+
         let options = match self.state.street {
-            Street::Preflop => self.compute_preflop_options(rng, profile, samples),
+            Street::Preflop => match self.state.hero_position {
+                Position::BigBlind => {
+                    self.compute_preflop_options_bb(rng, rival, &range, samples, max_enumeration, ev_deviation)
+                }
+                Position::Button => {
+                    self.compute_preflop_options_button(rng, rival, &range, samples, max_enumeration, ev_deviation)
+                }
+            },
             Street::Flop | Street::Turn | Street::River => {
-                self.compute_postflop_options(rng, profile, samples)
+                let mut ctx = PostflopCtx {
+                    rng,
+                    rival,
+                    samples,
+                    max_enumeration,
+                    ev_deviation,
+                };
+                self.compute_postflop_options(&mut ctx, &range)
             }
             Street::Showdown | Street::Terminal => Vec::new(),
         };
@@ -244,26 +499,38 @@ impl Hand {
         self.options = options;
     }
 
-    fn compute_preflop_options(
+    /// Options when hero is in the big blind and faces villain's (the button's) open.
+    fn compute_preflop_options_bb(
         &self,
         rng: &mut StdRng,
-        profile: RivalProfile,
+        rival: &dyn RivalStrategy,
+        range: &HandRange,
         samples: u32,
+        max_enumeration: u64,
+        ev_deviation: f32,
     ) -> Vec<ActionOption> {
         let hero_cards: [Card; 2] = self.hero;
-        let hero_strength = profile.hand_strength_hint(&hero_cards);
-        let equity = monte_carlo_equity(&self.hero, None, &[], samples, rng);
+        let hero_strength = hand_strength_hint(&hero_cards);
+        let equity = resolve_equity_vs_range(&self.hero, range, &[], samples, max_enumeration, rng);
         let call_cost = (self.open_size - self.state.hero_invested_bb).max(0.0);
         let pot_after_call = 2.0 * self.open_size;
         let call_ev = equity * pot_after_call - (1.0 - equity) * call_cost;
 
+        // Seed the search from the heuristic fixed 3-bet size.
         let pot_before_raise = self.state.pot_bb;
-        let raise_to = self.raise_size;
-        let raise_cost = (raise_to - self.state.hero_invested_bb).max(0.0);
-        let pot_when_called = 2.0 * raise_to;
-        let fold_prob = profile.fold_to_three_bet(hero_strength);
-        let raise_ev = fold_prob * pot_before_raise
-            + (1.0 - fold_prob) * (equity * pot_when_called - (1.0 - equity) * raise_cost);
+        let ctx = self.decision_context(Some(self.open_size), hero_strength, ev_deviation);
+        let fold_prob = rival.action_distribution(&ctx).fold;
+        let hero_invested = self.state.hero_invested_bb;
+        let max_raise = self.state.effective_stack_bb.max(self.raise_size);
+        let objective = |raise_to: f32| {
+            let raise_cost = (raise_to - hero_invested).max(0.0);
+            let pot_when_called = 2.0 * raise_to;
+            fold_prob * pot_before_raise
+                + (1.0 - fold_prob) * (equity * pot_when_called - (1.0 - equity) * raise_cost)
+        };
+        let result = anneal_bet_size(rng, self.raise_size, max_raise, objective);
+        let raise_to = result.best_size_bb;
+        let raise_ev = result.best_ev_bb;
 
         vec![
             ActionOption {
@@ -301,32 +568,101 @@ impl Hand {
         ]
     }
 
-    fn compute_postflop_options(
+    /// Options when hero is on the button, unopened pot: open-raise or fold the small
+    /// blind. Flatting the big blind isn't offered, mirroring how the existing
+    /// big-blind node only models the realistic +EV lines.
+    fn compute_preflop_options_button(
         &self,
         rng: &mut StdRng,
-        profile: RivalProfile,
+        rival: &dyn RivalStrategy,
+        range: &HandRange,
         samples: u32,
+        max_enumeration: u64,
+        ev_deviation: f32,
     ) -> Vec<ActionOption> {
+        let hero_cards: [Card; 2] = self.hero;
+        let hero_strength = hand_strength_hint(&hero_cards);
+        let equity = resolve_equity_vs_range(&self.hero, range, &[], samples, max_enumeration, rng);
+
+        let open_to = self.open_size;
+        let raise_cost = (open_to - self.state.hero_invested_bb).max(0.0);
+        let pot_when_called = 2.0 * open_to;
+        let ctx = self.decision_context(Some(open_to), hero_strength, ev_deviation);
+        let fold_prob = rival.action_distribution(&ctx).fold;
+        let open_ev = fold_prob * self.state.pot_bb
+            + (1.0 - fold_prob) * (equity * pot_when_called - (1.0 - equity) * raise_cost);
+
+        vec![
+            ActionOption {
+                action: HeroAction {
+                    kind: HeroActionKind::Fold,
+                    size_bb: None,
+                },
+                ev_delta_bb: -self.state.hero_invested_bb,
+                description: "Fold and surrender the small blind".to_string(),
+            },
+            ActionOption {
+                action: HeroAction {
+                    kind: HeroActionKind::Raise,
+                    size_bb: Some(open_to),
+                },
+                ev_delta_bb: open_ev,
+                description: format!(
+                    "Open-raise to {:.1}bb (fold equity {:.0}%)",
+                    open_to,
+                    fold_prob * 100.0
+                ),
+            },
+        ]
+    }
+
+    fn compute_postflop_options(&self, pf: &mut PostflopCtx, range: &HandRange) -> Vec<ActionOption> {
+        match self.state.facing_bet {
+            None => self.compute_postflop_options_initiative(pf, range),
+            Some(facing_bet) => self.compute_postflop_options_facing(pf, range, facing_bet),
+        }
+    }
+
+    /// Hero has the initiative on this street (first to act, or villain checked
+    /// behind): offers Check plus two distinct bet sizings, each solved independently
+    /// so "small" and "large" aren't just the same annealed size twice.
+    fn compute_postflop_options_initiative(&self, pf: &mut PostflopCtx, range: &HandRange) -> Vec<ActionOption> {
+        let rng = &mut *pf.rng;
+        let rival = pf.rival;
+        let ev_deviation = pf.ev_deviation;
+
         let board = self.visible_board();
-        let equity = monte_carlo_equity(&self.hero, None, &board, samples, rng);
+        let equity = resolve_equity_vs_range(&self.hero, range, &board, pf.samples, pf.max_enumeration, rng);
         let pot = self.state.pot_bb;
         let check_ev = (2.0 * equity - 1.0) * pot;
 
-        let bet_multiplier = match self.state.street {
+        // Seed the search from the heuristic pot-fraction sizes that used to be fixed.
+        let heuristic_multiplier = match self.state.street {
             Street::Flop => 0.5,
             Street::Turn => 0.6,
             Street::River => 0.75,
             _ => 0.5,
         };
-        let mut bet_size = (pot * bet_multiplier).max(0.5);
-        bet_size = bet_size.min(self.state.effective_stack_bb.max(0.0));
-        if bet_size < 0.5 {
-            bet_size = self.state.effective_stack_bb.max(0.0);
-        }
+        let max_bet = self.state.effective_stack_bb.max(0.5);
+        let fold_prob_for = |size: f32| -> f32 {
+            let decision_ctx = self.decision_context(Some(size), equity, ev_deviation);
+            rival.action_distribution(&decision_ctx).fold
+        };
+        let objective = |size: f32| {
+            let fold_prob = fold_prob_for(size);
+            fold_prob * pot + (1.0 - fold_prob) * (equity * (pot + 2.0 * size) - (1.0 - equity) * size)
+        };
 
-        let fold_prob = fold_probability(profile, equity, self.state.street);
-        let bet_ev = fold_prob * pot
-            + (1.0 - fold_prob) * (equity * (pot + 2.0 * bet_size) - (1.0 - equity) * bet_size);
+        let small_max = (max_bet * 0.66).max(0.5);
+        let small_initial = (pot * heuristic_multiplier * 0.6).max(0.5).min(small_max);
+        let small = anneal_bet_size(rng, small_initial, small_max, objective);
+
+        let large_initial = (pot * heuristic_multiplier * 1.3).max(small_max).min(max_bet);
+        let large = anneal_bet_size(rng, large_initial, max_bet, |size: f32| objective(size.max(small_max)));
+        let large_size = large.best_size_bb.max(small_max);
+
+        let small_fold_prob = fold_prob_for(small.best_size_bb);
+        let large_fold_prob = fold_prob_for(large_size);
 
         vec![
             ActionOption {
@@ -340,24 +676,122 @@ impl Hand {
             ActionOption {
                 action: HeroAction {
                     kind: HeroActionKind::Bet,
-                    size_bb: Some(bet_size),
+                    size_bb: Some(small.best_size_bb),
                 },
-                ev_delta_bb: bet_ev,
+                ev_delta_bb: small.best_ev_bb,
                 description: format!(
-                    "Bet {:.1}bb ({:.0}% fold equity)",
-                    bet_size,
-                    fold_prob * 100.0
+                    "Bet small {:.1}bb ({:.0}% fold equity)",
+                    small.best_size_bb,
+                    small_fold_prob * 100.0
+                ),
+            },
+            ActionOption {
+                action: HeroAction {
+                    kind: HeroActionKind::Bet,
+                    size_bb: Some(large_size),
+                },
+                ev_delta_bb: large.best_ev_bb,
+                description: format!(
+                    "Bet large {:.1}bb ({:.0}% fold equity)",
+                    large_size,
+                    large_fold_prob * 100.0
                 ),
             },
         ]
     }
 
+    /// Options when hero must respond to a bet or raise already on the table this
+    /// street: Fold, Call, and (unless the street's single raise has already been
+    /// used) Raise.
+    fn compute_postflop_options_facing(
+        &self,
+        pf: &mut PostflopCtx,
+        range: &HandRange,
+        facing_bet: f32,
+    ) -> Vec<ActionOption> {
+        let rng = &mut *pf.rng;
+        let rival = pf.rival;
+        let ev_deviation = pf.ev_deviation;
+
+        let board = self.visible_board();
+        let equity = resolve_equity_vs_range(&self.hero, range, &board, pf.samples, pf.max_enumeration, rng);
+        let pot = self.state.pot_bb;
+
+        let call_cost = facing_bet.min(self.state.effective_stack_bb.max(0.0));
+        let pot_after_call = pot + call_cost;
+        let call_ev = equity * pot_after_call - (1.0 - equity) * call_cost;
+
+        let mut options = vec![
+            ActionOption {
+                action: HeroAction {
+                    kind: HeroActionKind::Fold,
+                    size_bb: None,
+                },
+                ev_delta_bb: -self.state.hero_invested_bb,
+                description: "Fold and give up the pot".to_string(),
+            },
+            ActionOption {
+                action: HeroAction {
+                    kind: HeroActionKind::Call,
+                    size_bb: Some(call_cost),
+                },
+                ev_delta_bb: call_ev,
+                description: format!(
+                    "Call {:.1}bb (equity {:.1}%)",
+                    call_cost,
+                    equity * 100.0
+                ),
+            },
+        ];
+
+        // `size_bb` on the Raise option is the total incremental chips hero adds this
+        // action (the call plus the extra raise), matching the Bet convention above.
+        let room_to_raise = self.state.effective_stack_bb - call_cost;
+        if self.street_raises == 0 && room_to_raise > 0.5 {
+            let raise_extra_initial = (call_cost * 1.2).max(0.5).min(room_to_raise);
+            let fold_prob_for = |total_added: f32| -> f32 {
+                let ctx = self.decision_context(Some(total_added), equity, ev_deviation);
+                rival.action_distribution(&ctx).fold
+            };
+            let objective = |raise_extra: f32| {
+                let total_added = call_cost + raise_extra;
+                let fold_prob = fold_prob_for(total_added);
+                let hero_total_after = self.state.hero_invested_bb + total_added;
+                let pot_if_called = 2.0 * hero_total_after;
+                fold_prob * pot
+                    + (1.0 - fold_prob)
+                        * (equity * pot_if_called - (1.0 - equity) * total_added)
+            };
+            let result = anneal_bet_size(rng, raise_extra_initial, room_to_raise, objective);
+            let total_added = call_cost + result.best_size_bb;
+            let fold_prob = fold_prob_for(total_added);
+            let raise_to_total = self.state.hero_invested_bb + total_added;
+
+            options.push(ActionOption {
+                action: HeroAction {
+                    kind: HeroActionKind::Raise,
+                    size_bb: Some(total_added),
+                },
+                ev_delta_bb: result.best_ev_bb,
+                description: format!(
+                    "Raise to {:.1}bb ({:.0}% fold equity)",
+                    raise_to_total,
+                    fold_prob * 100.0
+                ),
+            });
+        }
+
+        options
+    }
+
     fn apply_action(
         &mut self,
         action: &HeroAction,
         rng: &mut StdRng,
-        profile: RivalProfile,
+        rival: &dyn RivalStrategy,
         samples: u32,
+        max_enumeration: u64,
+        ev_deviation: f32,
     ) -> HandProgress {
         let chosen = match self
             .options
@@ -372,11 +806,17 @@ impl Hand {
         self.total_chosen_ev += chosen.ev_delta_bb;
 
         match self.state.street {
-            Street::Preflop => self.apply_preflop(action, chosen, rng, profile),
-            Street::Flop | Street::Turn => {
-                self.apply_postflop(action, chosen, rng, profile, samples)
+            Street::Preflop => self.apply_preflop(action, chosen, rng, rival, ev_deviation),
+            Street::Flop | Street::Turn | Street::River => {
+                let mut ctx = PostflopCtx {
+                    rng,
+                    rival,
+                    samples,
+                    max_enumeration,
+                    ev_deviation,
+                };
+                self.apply_postflop(action, chosen, &mut ctx)
             }
-            Street::River => self.apply_river(action, chosen, rng, profile, samples),
             Street::Showdown | Street::Terminal => HandProgress::Completed(HandResult {
                 profit_bb: 0.0,
                 ev_loss_bb: self.current_ev_loss(),
@@ -389,7 +829,22 @@ impl Hand {
         action: &HeroAction,
         option: ActionOption,
         rng: &mut StdRng,
-        profile: RivalProfile,
+        rival: &dyn RivalStrategy,
+        ev_deviation: f32,
+    ) -> HandProgress {
+        match self.state.hero_position {
+            Position::BigBlind => self.apply_preflop_bb(action, option, rng, rival, ev_deviation),
+            Position::Button => self.apply_preflop_button(action, option, rng, rival, ev_deviation),
+        }
+    }
+
+    fn apply_preflop_bb(
+        &mut self,
+        action: &HeroAction,
+        option: ActionOption,
+        rng: &mut StdRng,
+        rival: &dyn RivalStrategy,
+        ev_deviation: f32,
     ) -> HandProgress {
         match action.kind {
             HeroActionKind::Fold => self.finish(-self.state.hero_invested_bb),
@@ -407,110 +862,215 @@ impl Hand {
                 self.refresh_state();
 
                 let hero_cards: [Card; 2] = self.hero;
-                let hero_strength = profile.hand_strength_hint(&hero_cards);
-                let fold_prob = profile.fold_to_three_bet(hero_strength);
-                if profile.random_fold(rng, fold_prob) {
-                    self.finish(self.state.villain_invested_bb)
-                } else {
-                    let call_cost = (raise_to - self.open_size).max(0.0);
-                    self.state.villain_invested_bb += call_cost;
-                    self.refresh_state();
-                    self.advance_street(Street::Flop);
-                    HandProgress::InProgress
+                let hero_strength = hand_strength_hint(&hero_cards);
+                let ctx = self.decision_context(Some(raise_to - self.open_size), hero_strength, ev_deviation);
+
+                // Preflop villain decisions never offer a re-raise in this model, so
+                // anything other than Fold is treated as a call.
+                match rival.decide(&ctx, rng) {
+                    RivalDecision::Fold => self.finish(self.state.villain_invested_bb),
+                    _ => {
+                        let call_cost = (raise_to - self.open_size).max(0.0);
+                        self.state.villain_invested_bb += call_cost;
+                        self.refresh_state();
+                        self.advance_street(Street::Flop);
+                        HandProgress::InProgress
+                    }
                 }
             }
             _ => HandProgress::InProgress,
         }
     }
 
-    fn apply_postflop(
+    fn apply_preflop_button(
         &mut self,
         action: &HeroAction,
         option: ActionOption,
         rng: &mut StdRng,
-        profile: RivalProfile,
-        samples: u32,
+        rival: &dyn RivalStrategy,
+        ev_deviation: f32,
+    ) -> HandProgress {
+        match action.kind {
+            HeroActionKind::Fold => self.finish(-self.state.hero_invested_bb),
+            HeroActionKind::Raise => {
+                let open_to = option.action.size_bb.unwrap_or(self.open_size);
+                let raise_cost = (open_to - self.state.hero_invested_bb).max(0.0);
+                self.state.hero_invested_bb += raise_cost;
+                self.refresh_state();
+
+                let hero_cards: [Card; 2] = self.hero;
+                let hero_strength = hand_strength_hint(&hero_cards);
+                let ctx = self.decision_context(Some(open_to), hero_strength, ev_deviation);
+
+                match rival.decide(&ctx, rng) {
+                    RivalDecision::Fold => self.finish(self.state.villain_invested_bb),
+                    _ => {
+                        let call_cost = (open_to - self.state.villain_invested_bb).max(0.0);
+                        self.state.villain_invested_bb += call_cost;
+                        self.refresh_state();
+                        self.advance_street(Street::Flop);
+                        HandProgress::InProgress
+                    }
+                }
+            }
+            _ => HandProgress::InProgress,
+        }
+    }
+
+    fn apply_postflop(
+        &mut self,
+        action: &HeroAction,
+        option: ActionOption,
+        pf: &mut PostflopCtx,
     ) -> HandProgress {
+        match self.state.facing_bet {
+            None => self.apply_postflop_initiative(action, option, pf),
+            Some(facing_bet) => self.apply_postflop_facing(action, option, pf, facing_bet),
+        }
+    }
+
+    fn apply_postflop_initiative(
+        &mut self,
+        action: &HeroAction,
+        option: ActionOption,
+        pf: &mut PostflopCtx,
+    ) -> HandProgress {
+        let rng = &mut *pf.rng;
+        let rival = pf.rival;
+        let ev_deviation = pf.ev_deviation;
+
         match action.kind {
             HeroActionKind::Check => {
-                let next = match self.state.street {
-                    Street::Flop => Street::Turn,
-                    Street::Turn => Street::River,
-                    _ => Street::River,
-                };
-                self.advance_street(next);
-                HandProgress::InProgress
+                // Equity isn't computed for this decision point (hero checked, so
+                // there's no bet size to weigh it against); only the lead frequency
+                // (which ignores hero_strength) matters here.
+                let ctx = self.decision_context(None, 0.5, ev_deviation);
+                match rival.decide(&ctx, rng) {
+                    RivalDecision::Lead { size_bb } => {
+                        let lead_size = size_bb
+                            .min((MAX_STACK_BB - self.state.villain_invested_bb).max(0.0))
+                            .max(0.0);
+                        if lead_size > 0.0 {
+                            self.state.villain_invested_bb += lead_size;
+                            self.refresh_state();
+                            self.state.facing_bet = Some(lead_size);
+                            return HandProgress::InProgress;
+                        }
+                        self.advance_after_street()
+                    }
+                    _ => self.advance_after_street(),
+                }
             }
             HeroActionKind::Bet => {
-                let mut bet_size = option.action.size_bb.unwrap_or(0.0);
-                if bet_size <= 0.0 {
-                    bet_size = (self.state.pot_bb * 0.5).max(0.5);
-                }
-                bet_size = bet_size.min(self.state.effective_stack_bb.max(0.0));
+                let pot_before_bet = self.state.pot_bb;
+                let bet_size = option
+                    .action
+                    .size_bb
+                    .unwrap_or(0.0)
+                    .max(0.5)
+                    .min(self.state.effective_stack_bb.max(0.0));
                 self.state.hero_invested_bb += bet_size;
                 self.refresh_state();
 
                 let board = self.visible_board();
-                let equity = monte_carlo_equity(&self.hero, None, &board, samples, rng);
-                let fold_prob = fold_probability(profile, equity, self.state.street);
+                let range = rival.range(self.state.street);
+                let equity =
+                    resolve_equity_vs_range(&self.hero, &range, &board, pf.samples, pf.max_enumeration, rng);
+                let mut ctx = self.decision_context(Some(bet_size), equity, ev_deviation);
+                ctx.pot_bb = pot_before_bet;
 
-                if profile.random_fold(rng, fold_prob) {
-                    self.finish(self.state.villain_invested_bb)
-                } else {
-                    let call_size =
-                        bet_size.min((MAX_STACK_BB - self.state.villain_invested_bb).max(0.0));
-                    self.state.villain_invested_bb += call_size;
-                    self.refresh_state();
-                    let next = match self.state.street {
-                        Street::Flop => Street::Turn,
-                        Street::Turn => Street::River,
-                        _ => Street::River,
-                    };
-                    self.advance_street(next);
-                    HandProgress::InProgress
+                match rival.decide(&ctx, rng) {
+                    RivalDecision::Fold => self.finish(self.state.villain_invested_bb),
+                    RivalDecision::Raise { size_bb } => {
+                        let raise_increment =
+                            size_bb.min((MAX_STACK_BB - self.state.villain_invested_bb).max(0.0));
+                        self.state.villain_invested_bb += raise_increment;
+                        self.refresh_state();
+                        self.state.facing_bet =
+                            Some(self.state.villain_invested_bb - self.state.hero_invested_bb);
+                        self.street_raises += 1;
+                        HandProgress::InProgress
+                    }
+                    _ => {
+                        let call_size =
+                            bet_size.min((MAX_STACK_BB - self.state.villain_invested_bb).max(0.0));
+                        self.state.villain_invested_bb += call_size;
+                        self.refresh_state();
+                        self.advance_after_street()
+                    }
                 }
             }
             _ => HandProgress::InProgress,
         }
     }
 
-    fn apply_river(
+    fn apply_postflop_facing(
         &mut self,
         action: &HeroAction,
         option: ActionOption,
-        rng: &mut StdRng,
-        profile: RivalProfile,
-        samples: u32,
+        pf: &mut PostflopCtx,
+        facing_bet: f32,
     ) -> HandProgress {
+        let rng = &mut *pf.rng;
+        let rival = pf.rival;
+        let ev_deviation = pf.ev_deviation;
+
         match action.kind {
-            HeroActionKind::Check => self.resolve_showdown(),
-            HeroActionKind::Bet => {
-                let mut bet_size = option.action.size_bb.unwrap_or(0.0);
-                if bet_size <= 0.0 {
-                    bet_size = (self.state.pot_bb * 0.75).max(0.5);
-                }
-                bet_size = bet_size.min(self.state.effective_stack_bb.max(0.0));
-                self.state.hero_invested_bb += bet_size;
+            HeroActionKind::Fold => self.finish(-self.state.hero_invested_bb),
+            HeroActionKind::Call => {
+                let call_cost = facing_bet.min(self.state.effective_stack_bb.max(0.0));
+                self.state.hero_invested_bb += call_cost;
+                self.refresh_state();
+                self.state.facing_bet = None;
+                self.advance_after_street()
+            }
+            HeroActionKind::Raise => {
+                let pot_before_raise = self.state.pot_bb;
+                let total_added = option.action.size_bb.unwrap_or(facing_bet);
+                self.state.hero_invested_bb += total_added;
                 self.refresh_state();
+                self.state.facing_bet = None;
+                self.street_raises += 1;
 
                 let board = self.visible_board();
-                let equity = monte_carlo_equity(&self.hero, None, &board, samples, rng);
-                let fold_prob = fold_probability(profile, equity, Street::River);
+                let range = rival.range(self.state.street);
+                let equity =
+                    resolve_equity_vs_range(&self.hero, &range, &board, pf.samples, pf.max_enumeration, rng);
+                let mut ctx = self.decision_context(Some(total_added), equity, ev_deviation);
+                ctx.pot_bb = pot_before_raise;
 
-                if profile.random_fold(rng, fold_prob) {
-                    self.finish(self.state.villain_invested_bb)
-                } else {
-                    let call_size =
-                        bet_size.min((MAX_STACK_BB - self.state.villain_invested_bb).max(0.0));
-                    self.state.villain_invested_bb += call_size;
-                    self.refresh_state();
-                    self.resolve_showdown()
+                match rival.decide(&ctx, rng) {
+                    RivalDecision::Fold => self.finish(self.state.villain_invested_bb),
+                    _ => {
+                        let call_size = (self.state.hero_invested_bb - self.state.villain_invested_bb)
+                            .max(0.0)
+                            .min((MAX_STACK_BB - self.state.villain_invested_bb).max(0.0));
+                        self.state.villain_invested_bb += call_size;
+                        self.refresh_state();
+                        self.advance_after_street()
+                    }
                 }
             }
             _ => HandProgress::InProgress,
         }
     }
 
+    /// Moves from the current street's completed betting round to the next decision
+    /// point: Turn after Flop, River after Turn, and showdown after River.
+    fn advance_after_street(&mut self) -> HandProgress {
+        match self.state.street {
+            Street::Flop => {
+                self.advance_street(Street::Turn);
+                HandProgress::InProgress
+            }
+            Street::Turn => {
+                self.advance_street(Street::River);
+                HandProgress::InProgress
+            }
+            _ => self.resolve_showdown(),
+        }
+    }
+
     fn resolve_showdown(&mut self) -> HandProgress {
         self.state.board_revealed = 5;
         self.state.street = Street::Showdown;
@@ -540,6 +1100,7 @@ impl Hand {
     fn node_snapshot(&self) -> NodeSnapshot {
         NodeSnapshot {
             street: self.state.street,
+            hero_position: self.state.hero_position,
             pot_bb: self.state.pot_bb,
             effective_stack_bb: self.state.effective_stack_bb.max(0.0),
             board: self
@@ -551,6 +1112,8 @@ impl Hand {
             hero_cards: self.hero.iter().map(|c| c.to_string()).collect(),
             rival_cards_known: self.completed,
             action_options: self.options.clone(),
+            draw: draw_summary(&self.hero, &self.visible_board()),
+            rival_range: if self.completed { None } else { self.rival_range.clone() },
         }
     }
 
@@ -571,6 +1134,8 @@ impl Hand {
             Street::Showdown | Street::Terminal => 5,
             Street::Preflop => 0,
         };
+        self.state.facing_bet = None;
+        self.street_raises = 0;
         self.refresh_state();
     }
 
@@ -594,22 +1159,15 @@ impl Hand {
     }
 }
 
+fn rotate(position: Position) -> Position {
+    match position {
+        Position::Button => Position::BigBlind,
+        Position::BigBlind => Position::Button,
+    }
+}
+
 fn effective_stack(hero_invested: f32, villain_invested: f32) -> f32 {
     let hero_remaining = (MAX_STACK_BB - hero_invested).max(0.0);
     let villain_remaining = (MAX_STACK_BB - villain_invested).max(0.0);
     hero_remaining.min(villain_remaining)
 }
-
-fn fold_probability(profile: RivalProfile, equity: f32, street: Street) -> f32 {
-    let (base, aggression_metric) = match street {
-        Street::Flop => (0.4, profile.continuation_bet_flop()),
-        Street::Turn => (0.35, profile.barrel_turn()),
-        Street::River => (0.3, profile.probe_river()),
-        _ => (0.45, 0.5),
-    };
-
-    let aggression_adjust = (0.5 - aggression_metric) * 0.3;
-    let equity_adjust = (0.5 - equity) * 0.35;
-    let raw = base + aggression_adjust + equity_adjust;
-    raw.clamp(0.05, 0.9)
-}
@@ -0,0 +1,111 @@
+use crate::game::Position;
+use crate::rival_strategy::StrategyRival;
+use crate::session::{Session, SessionConfig, SessionStatus};
+use crate::strategy::Strategy;
+
+/// Aggregate results of running a strategy over many independently dealt hands.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SimulationReport {
+    pub hands_played: u32,
+    pub total_ev_loss_bb: f32,
+    pub total_profit_bb: f32,
+    pub bb_per_100: f32,
+    pub profit_variance_bb: f32,
+    /// Of `hands_played`, how many dealt hero on `Position::BigBlind` vs
+    /// `Position::Button`. `run_with` alternates the dealt seat per hand so a run's
+    /// aggregate stats aren't skewed toward one position.
+    pub hands_as_big_blind: u32,
+    pub hands_as_button: u32,
+}
+
+/// Runs a hero `Strategy` against the built-in rival (selected via `SessionConfig`)
+/// over many hands and reports aggregate EV-loss, win-rate, and variance. Each hand is
+/// dealt as its own one-hand `Session`, seeded deterministically from the simulator's
+/// base seed so a run is reproducible. Hero's dealt seat alternates between
+/// `Position::BigBlind` and `Position::Button` across hands, since a fresh one-hand
+/// `Session` never reaches the in-session rotation that a multi-hand `Session` would.
+pub struct Simulator {
+    config: SessionConfig,
+}
+
+impl Simulator {
+    pub fn new(config: SessionConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn run<S: Strategy>(&self, hero: &mut S, hands: u32) -> SimulationReport {
+        self.run_with(hero, hands, Session::new_with_position)
+    }
+
+    /// Runs a `Strategy` matchup: `hero` plays the hero seat as in `run`, while `villain`
+    /// plays the villain seat via `rival_strategy::StrategyRival` (see its docs for the
+    /// adapter's limitations) instead of the built-in rival selected by `SessionConfig`.
+    /// `villain` is cloned fresh for each hand, the same way `Session::new` deals a fresh
+    /// hand each time, so a stateful villain strategy doesn't carry state across hands.
+    pub fn run_matchup<H: Strategy, V: Strategy + Clone + Send + 'static>(
+        &self,
+        hero: &mut H,
+        villain: &V,
+        hands: u32,
+    ) -> SimulationReport {
+        self.run_with(hero, hands, |config, position| {
+            Session::with_rival_and_position(config, Box::new(StrategyRival::new(villain.clone())), position)
+        })
+    }
+
+    fn run_with<S: Strategy>(
+        &self,
+        hero: &mut S,
+        hands: u32,
+        new_session: impl Fn(SessionConfig, Position) -> Session,
+    ) -> SimulationReport {
+        let base_seed = self.config.seed.unwrap_or_else(rand::random);
+        let mut total_ev_loss_bb = 0.0f32;
+        let mut profits = Vec::with_capacity(hands as usize);
+        let mut hands_as_big_blind = 0u32;
+        let mut hands_as_button = 0u32;
+
+        for i in 0..hands {
+            let mut config = self.config.clone();
+            config.hands = 1;
+            config.seed = Some(base_seed.wrapping_add(i as u64));
+            let position = if i % 2 == 0 { Position::BigBlind } else { Position::Button };
+            match position {
+                Position::BigBlind => hands_as_big_blind += 1,
+                Position::Button => hands_as_button += 1,
+            }
+
+            let mut session = new_session(config, position);
+            while session.snapshot().status != SessionStatus::Completed {
+                session.apply_strategy_action(hero);
+            }
+
+            let summary = session.snapshot().summary;
+            total_ev_loss_bb += summary.total_ev_loss_bb;
+            profits.push(summary.total_profit_bb);
+        }
+
+        let hands_played = profits.len() as u32;
+        let total_profit_bb: f32 = profits.iter().sum();
+        let mean_profit = if profits.is_empty() {
+            0.0
+        } else {
+            total_profit_bb / profits.len() as f32
+        };
+        let profit_variance_bb = if profits.is_empty() {
+            0.0
+        } else {
+            profits.iter().map(|p| (p - mean_profit).powi(2)).sum::<f32>() / profits.len() as f32
+        };
+
+        SimulationReport {
+            hands_played,
+            total_ev_loss_bb,
+            total_profit_bb,
+            bb_per_100: mean_profit * 100.0,
+            profit_variance_bb,
+            hands_as_big_blind,
+            hands_as_button,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::time::{Duration, Instant};
+
+use rand::Rng;
+use rand::rngs::StdRng;
+
+/// Wall-clock budget allotted to each simulated-annealing bet-size search. Kept small so
+/// a decision node resolves instantly while still running enough steps to converge.
+pub const SOLVER_TIME_BUDGET: Duration = Duration::from_millis(5);
+
+const MAX_STEPS: usize = 200;
+const MIN_BET_BB: f32 = 0.25;
+
+/// Result of a simulated-annealing search over a single bet-size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnealResult {
+    pub best_size_bb: f32,
+    pub best_ev_bb: f32,
+}
+
+/// Searches for the bet size (in bb, clamped to `[MIN_BET_BB, max_bb]`) that maximizes
+/// `objective`, starting from `initial_bb`. Each round perturbs the current candidate by
+/// a small Gaussian step, always accepts improvements, and accepts a worse candidate
+/// with probability `exp(delta_ev / temperature)` as temperature cools geometrically
+/// toward zero over `MAX_STEPS`, bailing early if `SOLVER_TIME_BUDGET` elapses. The step
+/// count (not elapsed time) drives the cooling schedule, so given the same `rng` state a
+/// search that runs all `MAX_STEPS` steps is deterministic. A search cut short by
+/// `SOLVER_TIME_BUDGET` runs fewer steps, so its result and the number of `rng` draws it
+/// consumes depend on how fast the machine is.
+pub fn anneal_bet_size<F>(rng: &mut StdRng, initial_bb: f32, max_bb: f32, objective: F) -> AnnealResult
+where
+    F: Fn(f32) -> f32,
+{
+    let max_bb = max_bb.max(MIN_BET_BB);
+    let start = Instant::now();
+
+    let mut current = initial_bb.clamp(MIN_BET_BB, max_bb);
+    let mut current_ev = objective(current);
+    let mut best = current;
+    let mut best_ev = current_ev;
+
+    let temperature_start = 1.0f32;
+    let temperature_end = 0.001f32;
+    let step_std_dev = (max_bb * 0.08).max(MIN_BET_BB * 0.5);
+
+    for step in 0..MAX_STEPS {
+        if start.elapsed() >= SOLVER_TIME_BUDGET {
+            break;
+        }
+
+        let progress = step as f32 / MAX_STEPS as f32;
+        let temperature = temperature_start * (temperature_end / temperature_start).powf(progress);
+
+        let candidate = (current + gaussian_step(rng, step_std_dev)).clamp(MIN_BET_BB, max_bb);
+        let candidate_ev = objective(candidate);
+        let delta_ev = candidate_ev - current_ev;
+
+        let accept = delta_ev >= 0.0 || rng.r#gen::<f32>() < (delta_ev / temperature).exp();
+        if accept {
+            current = candidate;
+            current_ev = candidate_ev;
+            if current_ev > best_ev {
+                best = current;
+                best_ev = current_ev;
+            }
+        }
+    }
+
+    AnnealResult {
+        best_size_bb: best,
+        best_ev_bb: best_ev,
+    }
+}
+
+fn gaussian_step(rng: &mut StdRng, std_dev: f32) -> f32 {
+    let u1: f32 = rng.gen_range(1e-6f32..1.0);
+    let u2: f32 = rng.gen_range(0.0f32..1.0);
+    let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f32::consts::PI * u2).cos();
+    z * std_dev
+}
@@ -0,0 +1,51 @@
+use rand::RngCore;
+use rand::seq::SliceRandom;
+
+use crate::game::{HeroAction, HeroActionKind, NodeSnapshot};
+
+/// A pluggable decision-maker for a seat at the table: given the current node (which
+/// already carries its offered `action_options`), choose one of them. Implementing this
+/// lets programmatic agents stand in for a human player, e.g. in a `Simulator` matchup.
+/// `rng` takes the same `&mut dyn RngCore` shape as `rival_strategy::RivalStrategy::decide`
+/// so a single `Strategy` impl can drive either seat (see `rival_strategy::StrategyRival`).
+pub trait Strategy {
+    fn decide(&mut self, snapshot: &NodeSnapshot, rng: &mut dyn RngCore) -> HeroAction;
+}
+
+fn fold() -> HeroAction {
+    HeroAction {
+        kind: HeroActionKind::Fold,
+        size_bb: None,
+    }
+}
+
+/// Always takes the option with the highest `ev_delta_bb`. This is the "best-EV" line
+/// the trainer grades hero's choices against.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BestEvStrategy;
+
+impl Strategy for BestEvStrategy {
+    fn decide(&mut self, snapshot: &NodeSnapshot, _rng: &mut dyn RngCore) -> HeroAction {
+        snapshot
+            .action_options
+            .iter()
+            .max_by(|a, b| a.ev_delta_bb.total_cmp(&b.ev_delta_bb))
+            .map(|opt| opt.action.clone())
+            .unwrap_or_else(fold)
+    }
+}
+
+/// Picks uniformly at random among the offered options. Useful as a simulator baseline
+/// to contrast against `BestEvStrategy`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RandomStrategy;
+
+impl Strategy for RandomStrategy {
+    fn decide(&mut self, snapshot: &NodeSnapshot, rng: &mut dyn RngCore) -> HeroAction {
+        snapshot
+            .action_options
+            .choose(rng)
+            .map(|opt| opt.action.clone())
+            .unwrap_or_else(fold)
+    }
+}
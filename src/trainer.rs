@@ -7,16 +7,25 @@ use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
 
 use crate::game::{HeroAction, HeroActionKind};
+use crate::replay::{ReplayExport, REPLAY_SCHEMA_VERSION};
 use crate::rival::RivalStyle;
 use crate::session::{Session, SessionConfig, SessionState, SessionStatus, SessionSummary};
+use crate::strategy::BestEvStrategy;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainerConfig {
     pub hands: u32,
     pub mc_samples: u32,
+    /// Largest exact-enumeration space `equity::resolve_equity_vs_range` will walk exactly;
+    /// see `SessionConfig::exact_equity_threshold`.
+    pub exact_equity_threshold: u64,
     pub seed: Option<u64>,
     #[serde(default)]
     pub rival_style: RivalStyle,
+    /// Registered `RivalStrategy` name (see `rival_strategy::strategies`), taking
+    /// precedence over `rival_style` when set.
+    #[serde(default)]
+    pub rival_strategy: Option<String>,
     #[serde(default)]
     pub no_color: bool,
 }
@@ -26,8 +35,10 @@ impl Default for TrainerConfig {
         Self {
             hands: 1,
             mc_samples: 200,
+            exact_equity_threshold: 50_000,
             seed: None,
             rival_style: RivalStyle::Balanced,
+            rival_strategy: None,
             no_color: false,
         }
     }
@@ -42,6 +53,7 @@ pub struct ActionChoice {
 pub struct Trainer {
     config: TrainerConfig,
     session: Session,
+    seed: u64,
     _rng: StdRng,
 }
 
@@ -51,17 +63,109 @@ impl Trainer {
         let session_config = SessionConfig {
             hands: config.hands,
             mc_samples: config.mc_samples,
+            exact_equity_threshold: config.exact_equity_threshold,
             rival_style: config.rival_style,
+            rival_strategy: config.rival_strategy.clone(),
             seed: Some(seed),
         };
         let session = Session::new(session_config);
         Self {
             config,
             session,
+            seed,
             _rng: StdRng::seed_from_u64(seed),
         }
     }
 
+    /// Serializes the session played so far into a versioned, self-contained JSON
+    /// export (decision trace plus the config needed to recreate it deterministically).
+    pub fn export_json(&self) -> Result<String> {
+        let export = ReplayExport {
+            schema_version: REPLAY_SCHEMA_VERSION,
+            seed: self.seed,
+            hands: self.config.hands,
+            mc_samples: self.config.mc_samples,
+            exact_equity_threshold: self.config.exact_equity_threshold,
+            rival_style: self.config.rival_style,
+            rival_strategy: self.config.rival_strategy.clone(),
+            replay: self.session.replay_log(),
+        };
+        Ok(serde_json::to_string_pretty(&export)?)
+    }
+
+    /// Rebuilds a `Trainer` from a JSON export produced by `export_json`, replaying
+    /// every recorded action back through a freshly seeded session and verifying the
+    /// node each action was taken against still matches what was recorded. Since the
+    /// session reuses the same seed and config, it deals the same cards and makes the
+    /// same rival decisions, so a verified replay reproduces the original session
+    /// deterministically; a mismatch (a stale export, or a tampered one) is reported
+    /// as an error instead of silently diverging. The returned `Trainer` picks up
+    /// exactly where the export left off, ready for further hands if any remain.
+    pub fn from_replay(json: &str) -> Result<Self> {
+        let export: ReplayExport = serde_json::from_str(json)?;
+        if export.schema_version != REPLAY_SCHEMA_VERSION {
+            anyhow::bail!(
+                "unsupported replay schema version {} (expected {})",
+                export.schema_version,
+                REPLAY_SCHEMA_VERSION
+            );
+        }
+
+        let config = TrainerConfig {
+            hands: export.hands,
+            mc_samples: export.mc_samples,
+            exact_equity_threshold: export.exact_equity_threshold,
+            seed: Some(export.seed),
+            rival_style: export.rival_style,
+            rival_strategy: export.rival_strategy.clone(),
+            no_color: false,
+        };
+        let session_config = SessionConfig {
+            hands: config.hands,
+            mc_samples: config.mc_samples,
+            exact_equity_threshold: config.exact_equity_threshold,
+            rival_style: config.rival_style,
+            rival_strategy: config.rival_strategy.clone(),
+            seed: Some(export.seed),
+        };
+        let mut session = Session::new(session_config);
+
+        for hand in &export.replay.hands {
+            for (entry_index, entry) in hand.entries.iter().enumerate() {
+                let snapshot = session.snapshot();
+                if snapshot.status != SessionStatus::AwaitingInput {
+                    anyhow::bail!(
+                        "replay mismatch: hand {} entry {} expected an in-progress session, \
+                         but it had already completed",
+                        hand.hand_index,
+                        entry_index
+                    );
+                }
+                let node = &snapshot.node;
+                if node.street != entry.street
+                    || node.pot_bb != entry.pot_bb
+                    || node.board != entry.board
+                    || node.action_options != entry.options
+                {
+                    anyhow::bail!(
+                        "replay mismatch: hand {} entry {} doesn't match the recorded node \
+                         (street/pot/board/options diverged)",
+                        hand.hand_index,
+                        entry_index
+                    );
+                }
+                session.apply_action(&entry.action_taken);
+            }
+        }
+
+        Ok(Self {
+            config,
+            session,
+            seed: export.seed,
+            _rng: StdRng::seed_from_u64(export.seed),
+        })
+    }
+
     pub fn run(&mut self) -> Result<()> {
         let mut input = String::new();
 
@@ -219,28 +323,30 @@ impl Trainer {
                 option.description
             );
         }
+        if let Some(draw) = &snapshot.node.draw {
+            println!(
+                "  Draw: {} outs, rule-of-{} estimate {:.1}% vs exact {:.1}%",
+                draw.outs,
+                if draw.cards_to_come == 2 { 4 } else { 2 },
+                draw.rule_estimate_pct,
+                draw.exact_pct
+            );
+            for (kind, count) in &draw.draw_types {
+                println!("    - {count} {}", kind.label());
+            }
+        }
         println!("Press the number of your choice, 'h' to view this help, or 'q' to quit.");
     }
 
     pub fn autoplay_best(&mut self) -> Result<SessionSummary> {
+        let mut strategy = BestEvStrategy;
         loop {
             let snapshot = self.session.snapshot();
             if snapshot.status == SessionStatus::Completed {
                 return Ok(snapshot.summary);
             }
 
-            let best_action = snapshot
-                .node
-                .action_options
-                .iter()
-                .max_by(|a, b| a.ev_delta_bb.total_cmp(&b.ev_delta_bb))
-                .map(|opt| opt.action.clone())
-                .unwrap_or(HeroAction {
-                    kind: HeroActionKind::Fold,
-                    size_bb: None,
-                });
-
-            self.session.apply_action(&best_action);
+            self.session.apply_strategy_action(&mut strategy);
         }
     }
 }
@@ -15,7 +15,9 @@ use tower_http::services::ServeDir;
 use uuid::Uuid;
 
 use crate::game::HeroAction;
+use crate::replay::SessionReplay;
 use crate::rival::RivalStyle;
+use crate::rival_strategy;
 use crate::session::{Session, SessionConfig, SessionState};
 
 #[derive(Clone)]
@@ -46,8 +48,12 @@ impl AppState {
 struct StartSessionRequest {
     hands: Option<u32>,
     mc_samples: Option<u32>,
+    exact_equity_threshold: Option<u64>,
     seed: Option<u64>,
     rival_style: Option<RivalStyle>,
+    /// Registered `RivalStrategy` name (see `rival_strategy::strategies`), taking
+    /// precedence over `rival_style` when set.
+    rival_strategy: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -64,12 +70,15 @@ struct ErrorResponse {
 enum ApiError {
     #[error("session not found")]
     NotFound,
+    #[error("{0}")]
+    UnknownRivalStrategy(String),
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         let status = match &self {
             ApiError::NotFound => StatusCode::NOT_FOUND,
+            ApiError::UnknownRivalStrategy(_) => StatusCode::BAD_REQUEST,
         };
         let body = Json(ErrorResponse {
             error: self.to_string(),
@@ -90,7 +99,8 @@ fn build_router(state: AppState) -> Router {
     let api = Router::new()
         .route("/sessions", post(start_session))
         .route("/sessions/:id", get(fetch_session))
-        .route("/sessions/:id/actions", post(apply_action));
+        .route("/sessions/:id/actions", post(apply_action))
+        .route("/sessions/:id/replay", get(fetch_replay));
 
     Router::new()
         .route("/healthz", get(health))
@@ -112,10 +122,22 @@ async fn start_session(
     State(state): State<AppState>,
     Json(req): Json<StartSessionRequest>,
 ) -> Result<Json<SessionState>, ApiError> {
+    if let Some(name) = &req.rival_strategy {
+        if rival_strategy::resolve_strategy(name).is_none() {
+            return Err(ApiError::UnknownRivalStrategy(format!(
+                "unknown rival style '{}' (available: {})",
+                name,
+                rival_strategy::strategies().join(", ")
+            )));
+        }
+    }
+
     let config = SessionConfig {
         hands: req.hands.unwrap_or(1),
         mc_samples: req.mc_samples.unwrap_or(200),
+        exact_equity_threshold: req.exact_equity_threshold.unwrap_or(50_000),
         rival_style: req.rival_style.unwrap_or(RivalStyle::Balanced),
+        rival_strategy: req.rival_strategy,
         seed: req.seed,
     };
 
@@ -135,6 +157,15 @@ async fn fetch_session(
     Ok(Json(session.snapshot()))
 }
 
+async fn fetch_replay(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<SessionReplay>, ApiError> {
+    let session_arc = state.get_session(&id).ok_or(ApiError::NotFound)?;
+    let session = session_arc.lock();
+    Ok(Json(session.replay_log()))
+}
+
 async fn apply_action(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
@@ -6,8 +6,10 @@ fn autoplay_completes_requested_hands() {
     let config = TrainerConfig {
         hands: 2,
         mc_samples: 100,
+        exact_equity_threshold: 50_000,
         seed: Some(1234),
         rival_style: RivalStyle::Aggressive,
+        rival_strategy: None,
         no_color: true,
     };
 
@@ -15,3 +15,25 @@ fn cli_auto_mode_runs_to_completion() {
         .success()
         .stdout(predicates::str::contains("Summary"));
 }
+
+#[test]
+fn cli_export_flag_writes_a_json_hand_history() {
+    let export_path = std::env::temp_dir().join("gto_trainer_cli_export_test.json");
+    let _ = std::fs::remove_file(&export_path);
+
+    let mut cmd = Command::cargo_bin("gto-trainer").expect("binary exists");
+    cmd.arg("--hands")
+        .arg("1")
+        .arg("--mc")
+        .arg("50")
+        .arg("--no-color")
+        .arg("--auto")
+        .arg("--export")
+        .arg(&export_path);
+
+    cmd.assert().success();
+
+    let exported = std::fs::read_to_string(&export_path).expect("export file written");
+    assert!(exported.contains("\"hands\""));
+    let _ = std::fs::remove_file(&export_path);
+}
@@ -2,7 +2,10 @@ use rand::SeedableRng;
 use rand::rngs::StdRng;
 
 use gto_trainer::cards::{Card, Rank, Suit};
-use gto_trainer::equity::{HandCategory, best_five_card_hand, monte_carlo_equity};
+use gto_trainer::equity::{
+    DrawKind, HandCategory, best_five_card_hand, draw_summary, enumeration_size, exact_equity,
+    monte_carlo_equity, resolve_equity,
+};
 
 #[test]
 fn quads_outrank_full_house() {
@@ -60,3 +63,129 @@ fn monte_carlo_respects_known_board() {
     let equity = monte_carlo_equity(&hero, Some(&villain), &board, 5_000, &mut rng);
     assert!(equity > 0.97, "equity={equity}");
 }
+
+#[test]
+fn draw_summary_counts_outs_and_cross_checks_rule_of_four() {
+    // Hero has a flush draw on the flop (four hearts among the five known cards).
+    let hero = [
+        Card::new(Rank::Ace, Suit::Hearts),
+        Card::new(Rank::King, Suit::Hearts),
+    ];
+    let board = vec![
+        Card::new(Rank::Two, Suit::Hearts),
+        Card::new(Rank::Seven, Suit::Hearts),
+        Card::new(Rank::Nine, Suit::Clubs),
+    ];
+
+    let draw = draw_summary(&hero, &board).expect("flop has a draw to analyse");
+    assert_eq!(draw.cards_to_come, 2);
+    // At least the 9 remaining hearts complete the flush.
+    assert!(draw.outs >= 9, "outs={}", draw.outs);
+    assert_eq!(draw.rule_estimate_pct, (draw.outs as f32 * 4.0).min(100.0));
+    assert!(draw.exact_pct > 0.0 && draw.exact_pct <= 100.0);
+
+    let flush_outs = draw
+        .draw_types
+        .iter()
+        .find(|(kind, _)| *kind == DrawKind::FlushDraw)
+        .map(|(_, count)| *count);
+    assert_eq!(flush_outs, Some(9));
+}
+
+#[test]
+fn exact_equity_splits_a_chopped_river() {
+    // Board plays a 2-6 straight for both hands; neither hole-card pair improves on it,
+    // so the pot chops and exact_equity should land exactly on 0.5 with no sampling noise.
+    let hero = [
+        Card::new(Rank::King, Suit::Hearts),
+        Card::new(Rank::Queen, Suit::Diamonds),
+    ];
+    let villain = [
+        Card::new(Rank::Jack, Suit::Spades),
+        Card::new(Rank::Ten, Suit::Clubs),
+    ];
+    let board = vec![
+        Card::new(Rank::Two, Suit::Clubs),
+        Card::new(Rank::Three, Suit::Diamonds),
+        Card::new(Rank::Four, Suit::Hearts),
+        Card::new(Rank::Five, Suit::Spades),
+        Card::new(Rank::Six, Suit::Clubs),
+    ];
+
+    let equity = exact_equity(&hero, Some(&villain), &board);
+    assert_eq!(equity, 0.5);
+}
+
+#[test]
+fn resolve_equity_picks_exact_enumeration_below_threshold() {
+    let hero = [
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::Ace, Suit::Hearts),
+    ];
+    let villain = [
+        Card::new(Rank::King, Suit::Spades),
+        Card::new(Rank::King, Suit::Hearts),
+    ];
+    let board = vec![
+        Card::new(Rank::Two, Suit::Clubs),
+        Card::new(Rank::Seven, Suit::Diamonds),
+        Card::new(Rank::Nine, Suit::Hearts),
+        Card::new(Rank::Four, Suit::Spades),
+    ];
+
+    // River still to come, villain known: enumeration_size is C(44, 1) = 44, well under a
+    // generous threshold, so both calls should agree on the exact (noise-free) figure.
+    assert_eq!(enumeration_size(true, 44, 1), 44);
+
+    let mut rng = StdRng::seed_from_u64(3);
+    let resolved = resolve_equity(&hero, Some(&villain), &board, 500, 1_000, &mut rng);
+    let exact = exact_equity(&hero, Some(&villain), &board);
+    assert_eq!(resolved, exact);
+
+    // With the threshold dropped below the 44-combo river space, resolve_equity must fall
+    // back to Monte Carlo instead, which only approximates the exact figure.
+    let mut rng = StdRng::seed_from_u64(3);
+    let sampled = resolve_equity(&hero, Some(&villain), &board, 2_000, 0, &mut rng);
+    assert!((sampled - exact).abs() < 0.05, "sampled={sampled} exact={exact}");
+}
+
+#[test]
+fn draw_summary_is_none_preflop_and_on_the_river() {
+    let hero = [
+        Card::new(Rank::Ace, Suit::Hearts),
+        Card::new(Rank::King, Suit::Hearts),
+    ];
+    assert!(draw_summary(&hero, &[]).is_none());
+
+    let full_board = vec![
+        Card::new(Rank::Two, Suit::Hearts),
+        Card::new(Rank::Seven, Suit::Hearts),
+        Card::new(Rank::Nine, Suit::Clubs),
+        Card::new(Rank::Three, Suit::Diamonds),
+        Card::new(Rank::Four, Suit::Spades),
+    ];
+    assert!(draw_summary(&hero, &full_board).is_none());
+}
+
+#[test]
+fn draw_summary_distinguishes_open_ended_from_overcards() {
+    // J-T offsuit on a 9-8-2 rainbow flop: a true open-ended straight draw (any 7 or Q
+    // makes the straight), no pair yet so J and T also count as overcards.
+    let hero = [
+        Card::new(Rank::Jack, Suit::Hearts),
+        Card::new(Rank::Ten, Suit::Diamonds),
+    ];
+    let board = vec![
+        Card::new(Rank::Nine, Suit::Clubs),
+        Card::new(Rank::Eight, Suit::Diamonds),
+        Card::new(Rank::Two, Suit::Spades),
+    ];
+
+    let draw = draw_summary(&hero, &board).expect("flop has a draw to analyse");
+    let find = |kind: DrawKind| draw.draw_types.iter().find(|(k, _)| *k == kind).map(|(_, n)| *n);
+
+    assert_eq!(find(DrawKind::OpenEndedStraight), Some(8));
+    assert_eq!(find(DrawKind::Gutshot), None);
+    assert_eq!(find(DrawKind::FlushDraw), None);
+    assert_eq!(find(DrawKind::Overcards), Some(6));
+}
@@ -0,0 +1,53 @@
+use gto_trainer::game::{HeroActionKind, Position};
+use gto_trainer::rival::RivalStyle;
+use gto_trainer::session::{Session, SessionConfig};
+
+#[test]
+fn dealer_rotates_and_button_posts_small_blind() {
+    let mut session = Session::new(SessionConfig {
+        hands: 2,
+        mc_samples: 80,
+        exact_equity_threshold: 50_000,
+        rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
+        seed: Some(2025),
+    });
+
+    let first = session.snapshot();
+    assert_eq!(first.node.hero_position, Position::BigBlind);
+    assert!(
+        first
+            .node
+            .action_options
+            .iter()
+            .any(|opt| opt.action.kind == HeroActionKind::Call)
+    );
+
+    let fold = first
+        .node
+        .action_options
+        .iter()
+        .find(|opt| opt.action.kind == HeroActionKind::Fold)
+        .expect("fold option")
+        .action
+        .clone();
+    session.apply_action(&fold);
+
+    let second = session.snapshot();
+    assert_eq!(second.node.hero_position, Position::Button);
+    assert_eq!(second.node.pot_bb, 1.5);
+    assert!(
+        second
+            .node
+            .action_options
+            .iter()
+            .any(|opt| opt.action.kind == HeroActionKind::Raise)
+    );
+    assert!(
+        !second
+            .node
+            .action_options
+            .iter()
+            .any(|opt| opt.action.kind == HeroActionKind::Call)
+    );
+}
@@ -0,0 +1,65 @@
+use gto_trainer::game::HeroActionKind;
+use gto_trainer::rival::RivalStyle;
+use gto_trainer::session::{Session, SessionConfig, SessionStatus};
+
+/// Picks Check when available, otherwise Call, otherwise Raise, otherwise Fold - this
+/// keeps hands alive long enough to reach postflop facing-bet decisions without ever
+/// needing to know villain's hidden cards.
+fn pick_action(options: &[gto_trainer::game::ActionOption]) -> gto_trainer::game::HeroAction {
+    for kind in [
+        HeroActionKind::Check,
+        HeroActionKind::Call,
+        HeroActionKind::Raise,
+        HeroActionKind::Fold,
+    ] {
+        if let Some(opt) = options.iter().find(|opt| opt.action.kind == kind) {
+            return opt.action.clone();
+        }
+    }
+    unreachable!("every node offers at least a Check or a Fold option")
+}
+
+#[test]
+fn villain_can_lead_and_hero_can_face_a_bet_postflop() {
+    let mut saw_facing_bet = false;
+
+    for seed in 0u64..40 {
+        let config = SessionConfig {
+            hands: 1,
+            mc_samples: 60,
+            exact_equity_threshold: 50_000,
+            rival_style: RivalStyle::Balanced,
+            rival_strategy: None,
+            seed: Some(seed),
+        };
+        let mut session = Session::new(config);
+
+        loop {
+            let state = session.snapshot();
+            if state.status == SessionStatus::Completed {
+                break;
+            }
+
+            if state.node.street != gto_trainer::game::Street::Preflop
+                && state
+                    .node
+                    .action_options
+                    .iter()
+                    .any(|opt| opt.action.kind == HeroActionKind::Call)
+            {
+                saw_facing_bet = true;
+            }
+
+            let action = pick_action(&state.node.action_options);
+            session.apply_action(&action);
+        }
+
+        let summary = session.snapshot().summary;
+        assert_eq!(summary.hands_played, 1);
+    }
+
+    assert!(
+        saw_facing_bet,
+        "expected at least one seed where villain led or raised into hero postflop"
+    );
+}
@@ -0,0 +1,72 @@
+use gto_trainer::cards::{Card, Rank, Suit};
+use gto_trainer::range::HandRange;
+
+#[test]
+fn pair_plus_expands_every_pair_at_or_above() {
+    let range = HandRange::parse("TT+").expect("valid notation");
+    // TT, JJ, QQ, KK, AA: 5 ranks * 6 combos each.
+    assert_eq!(range.combos().len(), 30);
+    assert!(
+        range
+            .combos()
+            .iter()
+            .all(|c| c.cards.0.rank == c.cards.1.rank)
+    );
+}
+
+#[test]
+fn suited_connector_dash_expands_the_constant_gap_run() {
+    // T9s-76s should name every one-gap suited connector from 76s up through T9s:
+    // 76s, 87s, 98s, T9s - 4 classes * 4 suits each.
+    let dash = HandRange::parse("T9s-76s").expect("valid notation");
+    assert_eq!(dash.combos().len(), 16);
+
+    let ten_nine_suited = Card::new(Rank::Ten, Suit::Spades);
+    let nine_suited = Card::new(Rank::Nine, Suit::Spades);
+    assert!(dash.combos().iter().any(|c| c.cards == (ten_nine_suited, nine_suited)));
+
+    let seven_suited = Card::new(Rank::Seven, Suit::Clubs);
+    let six_suited = Card::new(Rank::Six, Suit::Clubs);
+    assert!(dash.combos().iter().any(|c| c.cards == (seven_suited, six_suited)));
+}
+
+#[test]
+fn ace_kicker_plus_expands_every_suited_combo_with_that_top_card() {
+    let plus = HandRange::parse("A2s+").expect("valid notation");
+    // A2s..AKs: 12 classes * 4 suits each.
+    assert_eq!(plus.combos().len(), 48);
+
+    let ace_king_suited = Card::new(Rank::Ace, Suit::Spades);
+    let king_suited = Card::new(Rank::King, Suit::Spades);
+    assert!(
+        plus.combos()
+            .iter()
+            .any(|c| c.cards == (ace_king_suited, king_suited))
+    );
+}
+
+#[test]
+fn unsuffixed_class_includes_both_suited_and_offsuit_combos() {
+    let range = HandRange::parse("AK").expect("valid notation");
+    // 4 suited + 12 offsuit combos of Ace-King.
+    assert_eq!(range.combos().len(), 16);
+}
+
+#[test]
+fn combos_excluding_filters_out_blocked_cards() {
+    let range = HandRange::parse("AA").expect("valid notation");
+    let blocker = [Card::new(Rank::Ace, Suit::Spades)];
+    let live = range.combos_excluding(&blocker);
+    // Every AA combo uses one of the 4 suits; blocking one suit removes the 3 combos
+    // that used it, leaving only the 3 combos made from the other three suits.
+    assert_eq!(live.len(), 3);
+    assert!(
+        live.iter()
+            .all(|c| c.cards.0 != blocker[0] && c.cards.1 != blocker[0])
+    );
+}
+
+#[test]
+fn full_range_covers_the_entire_1326_combo_space() {
+    assert_eq!(HandRange::full().combos().len(), 1326);
+}
@@ -0,0 +1,39 @@
+use gto_trainer::game::HeroActionKind;
+use gto_trainer::rival::RivalStyle;
+use gto_trainer::session::{Session, SessionConfig};
+
+#[test]
+fn replay_log_records_each_decision_and_final_result() {
+    let mut session = Session::new(SessionConfig {
+        hands: 1,
+        mc_samples: 100,
+        exact_equity_threshold: 50_000,
+        rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
+        seed: Some(42),
+    });
+
+    let initial = session.snapshot();
+    let fold_action = initial
+        .node
+        .action_options
+        .iter()
+        .find(|opt| opt.action.kind == HeroActionKind::Fold)
+        .expect("fold option available")
+        .action
+        .clone();
+
+    session.apply_action(&fold_action);
+
+    let replay = session.replay_log();
+    assert_eq!(replay.session_id, session.id());
+    assert_eq!(replay.hands.len(), 1);
+
+    let hand = &replay.hands[0];
+    assert_eq!(hand.hand_index, 1);
+    assert_eq!(hand.entries.len(), 1);
+    assert_eq!(hand.entries[0].action_taken.kind, HeroActionKind::Fold);
+    assert!(!hand.entries[0].options.is_empty());
+    assert_eq!(hand.villain_cards.len(), 2);
+    assert_eq!(hand.profit_bb, session.snapshot().summary.total_profit_bb);
+}
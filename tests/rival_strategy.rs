@@ -0,0 +1,38 @@
+use gto_trainer::game::{Position, Street};
+use gto_trainer::rival_strategy::{self, DecisionContext, RivalStrategy, TrackerStrategy};
+use gto_trainer::rival::RivalStyle;
+
+fn flop_context(hero_ev_deviation_bb: f32) -> DecisionContext {
+    DecisionContext {
+        street: Street::Flop,
+        hero_position: Position::Button,
+        facing_bet_bb: Some(5.0),
+        pot_bb: 10.0,
+        hero_invested_bb: 10.0,
+        villain_invested_bb: 10.0,
+        effective_stack_bb: 90.0,
+        street_raises: 0,
+        hero_strength: 0.6,
+        hero_ev_deviation_bb,
+    }
+}
+
+#[test]
+fn registry_lists_all_built_in_strategies_by_name() {
+    let names = rival_strategy::strategies();
+    for expected in ["balanced", "aggressive", "passive", "tracker"] {
+        assert!(names.contains(&expected), "missing {expected} in {names:?}");
+    }
+    assert!(rival_strategy::resolve_strategy("unknown-style").is_none());
+    assert!(rival_strategy::resolve_strategy("tracker").is_some());
+}
+
+#[test]
+fn tracker_presses_harder_as_hero_ev_deviation_grows() {
+    let tracker = TrackerStrategy::new(RivalStyle::Balanced);
+    let steady = tracker.action_distribution(&flop_context(0.0));
+    let exploitable = tracker.action_distribution(&flop_context(1.0));
+
+    assert!(exploitable.fold < steady.fold);
+    assert!(exploitable.raise >= steady.raise);
+}
@@ -7,7 +7,9 @@ fn calling_preflop_advances_to_flop() {
     let mut session = Session::new(SessionConfig {
         hands: 1,
         mc_samples: 200,
+        exact_equity_threshold: 50_000,
         rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
         seed: Some(2025),
     });
 
@@ -38,7 +40,9 @@ fn session_rolls_into_next_hand_after_completion() {
     let mut session = Session::new(SessionConfig {
         hands: 2,
         mc_samples: 150,
+        exact_equity_threshold: 50_000,
         rival_style: RivalStyle::Passive,
+        rival_strategy: None,
         seed: Some(11),
     });
 
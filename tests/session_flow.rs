@@ -7,7 +7,9 @@ fn folding_ends_session_and_records_summary() {
     let config = SessionConfig {
         hands: 1,
         mc_samples: 100,
+        exact_equity_threshold: 50_000,
         rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
         seed: Some(42),
     };
 
@@ -0,0 +1,62 @@
+use gto_trainer::rival::RivalStyle;
+use gto_trainer::session::SessionConfig;
+use gto_trainer::strategy::{BestEvStrategy, RandomStrategy};
+use gto_trainer::simulator::Simulator;
+
+#[test]
+fn simulator_runs_requested_hand_count_and_reports_aggregate_ev() {
+    let simulator = Simulator::new(SessionConfig {
+        hands: 1,
+        mc_samples: 60,
+        exact_equity_threshold: 50_000,
+        rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
+        seed: Some(777),
+    });
+
+    let mut strategy = BestEvStrategy;
+    let report = simulator.run(&mut strategy, 20);
+
+    assert_eq!(report.hands_played, 20);
+    assert!(report.total_ev_loss_bb >= 0.0);
+    assert!(report.profit_variance_bb >= 0.0);
+}
+
+#[test]
+fn simulator_runs_a_strategy_matchup_on_both_seats() {
+    let simulator = Simulator::new(SessionConfig {
+        hands: 1,
+        mc_samples: 60,
+        exact_equity_threshold: 50_000,
+        rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
+        seed: Some(1234),
+    });
+
+    let mut hero = BestEvStrategy;
+    let villain = RandomStrategy;
+    let report = simulator.run_matchup(&mut hero, &villain, 20);
+
+    assert_eq!(report.hands_played, 20);
+    assert!(report.total_ev_loss_bb >= 0.0);
+    assert!(report.profit_variance_bb >= 0.0);
+}
+
+#[test]
+fn simulator_alternates_hero_between_both_seats() {
+    let simulator = Simulator::new(SessionConfig {
+        hands: 1,
+        mc_samples: 60,
+        exact_equity_threshold: 50_000,
+        rival_style: RivalStyle::Balanced,
+        rival_strategy: None,
+        seed: Some(777),
+    });
+
+    let mut strategy = BestEvStrategy;
+    let report = simulator.run(&mut strategy, 20);
+
+    assert_eq!(report.hands_as_big_blind + report.hands_as_button, 20);
+    assert!(report.hands_as_big_blind > 0, "big blind seat never dealt");
+    assert!(report.hands_as_button > 0, "button seat never dealt");
+}
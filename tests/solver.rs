@@ -0,0 +1,16 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use gto_trainer::solver::anneal_bet_size;
+
+#[test]
+fn anneal_bet_size_converges_to_interior_optimum() {
+    let mut rng = StdRng::seed_from_u64(2025);
+    // A simple concave objective with a known maximum at x = 6.0.
+    let objective = |x: f32| -((x - 6.0).powi(2)) + 20.0;
+
+    let result = anneal_bet_size(&mut rng, 1.0, 12.0, objective);
+
+    assert!((result.best_size_bb - 6.0).abs() < 1.0, "best={}", result.best_size_bb);
+    assert!(result.best_ev_bb > objective(1.0));
+}
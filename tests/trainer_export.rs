@@ -0,0 +1,47 @@
+use gto_trainer::replay::REPLAY_SCHEMA_VERSION;
+use gto_trainer::{Trainer, TrainerConfig};
+
+#[test]
+fn export_json_round_trips_into_a_verified_replay() {
+    let config = TrainerConfig {
+        hands: 2,
+        mc_samples: 60,
+        seed: Some(2025),
+        ..TrainerConfig::default()
+    };
+    let mut trainer = Trainer::new(config);
+    let summary = trainer.autoplay_best().expect("autoplay completes");
+    assert_eq!(summary.hands_played, 2);
+
+    let exported = trainer.export_json().expect("export succeeds");
+    assert!(exported.contains(&format!("\"schema_version\": {}", REPLAY_SCHEMA_VERSION)));
+
+    let mut replayed = Trainer::from_replay(&exported).expect("recorded actions replay cleanly");
+    let replayed_summary = replayed.summary();
+    assert_eq!(replayed_summary.hands_played, summary.hands_played);
+    assert_eq!(replayed_summary.total_profit_bb, summary.total_profit_bb);
+    assert_eq!(replayed_summary.total_ev_loss_bb, summary.total_ev_loss_bb);
+}
+
+#[test]
+fn from_replay_rejects_a_tampered_entry() {
+    let config = TrainerConfig {
+        hands: 1,
+        mc_samples: 60,
+        seed: Some(99),
+        ..TrainerConfig::default()
+    };
+    let mut trainer = Trainer::new(config);
+    trainer.autoplay_best().expect("autoplay completes");
+    let exported = trainer.export_json().expect("export succeeds");
+
+    let mut value: serde_json::Value = serde_json::from_str(&exported).expect("export is valid json");
+    let pot_bb = value["replay"]["hands"][0]["entries"][0]["pot_bb"]
+        .as_f64()
+        .expect("first entry has a pot_bb");
+    value["replay"]["hands"][0]["entries"][0]["pot_bb"] = serde_json::json!(pot_bb + 100.0);
+    let tampered = serde_json::to_string(&value).expect("tampered value serializes");
+
+    let result = Trainer::from_replay(&tampered);
+    assert!(result.is_err(), "tampered replay entry should fail verification");
+}
@@ -1,7 +1,8 @@
 use std::net::SocketAddr;
 
 use axum::Router;
-use gto_trainer::game::HeroAction;
+use gto_trainer::game::{HeroAction, HeroActionKind};
+use gto_trainer::replay::SessionReplay;
 use gto_trainer::session::{SessionState, SessionStatus};
 use gto_trainer::web;
 use reqwest::Client;
@@ -61,6 +62,71 @@ async fn web_api_supports_session_flow() -> anyhow::Result<()> {
         SessionStatus::AwaitingInput | SessionStatus::Completed
     ));
 
+    while state.status == SessionStatus::AwaitingInput {
+        let fold = state
+            .node
+            .action_options
+            .iter()
+            .find(|opt| opt.action.kind == HeroActionKind::Fold)
+            .expect("fold option available")
+            .action
+            .clone();
+
+        state = client
+            .post(format!(
+                "{}/api/sessions/{}/actions",
+                base_url, state.session_id
+            ))
+            .json(&ActionPayload { action: fold })
+            .send()
+            .await?
+            .json()
+            .await?;
+    }
+
+    let replay: SessionReplay = client
+        .get(format!(
+            "{}/api/sessions/{}/replay",
+            base_url, state.session_id
+        ))
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    assert_eq!(replay.session_id, state.session_id);
+    assert_eq!(replay.hands.len(), state.summary.hands_played as usize);
+
+    server.abort();
+    let _ = server.await;
+    Ok(())
+}
+
+#[tokio::test]
+async fn start_session_rejects_an_unknown_rival_strategy() -> anyhow::Result<()> {
+    let app: Router = web::router();
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
+    let addr: SocketAddr = listener.local_addr()?;
+    let server = tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    let base_url = format!("http://{}", addr);
+    let client = Client::builder().build()?;
+
+    sleep(Duration::from_millis(25)).await;
+
+    let response = client
+        .post(format!("{}/api/sessions", base_url))
+        .json(&json!({
+            "hands": 1,
+            "rival_strategy": "not-a-real-strategy"
+        }))
+        .send()
+        .await?;
+
+    assert_eq!(response.status(), reqwest::StatusCode::BAD_REQUEST);
+
     server.abort();
     let _ = server.await;
     Ok(())